@@ -48,3 +48,96 @@ impl<T> Into<GetOptions> for ZomeFnInput<T> {
         }
     }
 }
+
+/// Whether the agent has validly published their agent key to the DHT of this DNA,
+/// i.e. whether they have appeared in the network as a member at all. Agent keys
+/// that have been fetched from the network are kept in the local cache, so the
+/// network only gets queried for agents that have not been seen before.
+pub fn has_appeared_in_network(agent: &AgentPubKey) -> ExternResult<bool> {
+    if agent == &agent_info()?.agent_initial_pubkey {
+        return Ok(true);
+    }
+    let agent_entry_hash = EntryHash::from(agent.clone());
+    if get(agent_entry_hash.clone(), GetOptions::local())?.is_some() {
+        return Ok(true);
+    }
+    Ok(get(agent_entry_hash, GetOptions::network())?.is_some())
+}
+
+/// Input of list functions that return their results in pages
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PaginationInput {
+    /// The cursor returned with the previous page. None to get the first page.
+    pub cursor: Option<PageCursor>,
+    /// Maximum number of items of the page, must be greater than 0
+    pub limit: u32,
+}
+
+/// Position in a list whose links are spread across several bucket anchors
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PageCursor {
+    /// Index of the anchor in which the previous page ended
+    pub bucket: u32,
+    /// The last link target that has been returned from that anchor
+    pub last_target: AnyLinkableHash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor to get the next page with. None if this is the last page.
+    pub next_cursor: Option<PageCursor>,
+}
+
+/// Gets a page of the link targets of a list whose links are spread across the given
+/// anchors. The anchors are visited in the given order and the targets of each anchor
+/// in the order of their hashes, so pages are stable while links get added to anchors
+/// that have already been visited. Duplicate targets within an anchor are returned once.
+/// The limit must be greater than 0.
+pub fn get_bucketed_targets_page<F>(
+    anchors: &[EntryHash],
+    pagination: &PaginationInput,
+    mut get_targets: F,
+) -> ExternResult<Page<AnyLinkableHash>>
+where
+    F: FnMut(&EntryHash) -> ExternResult<Vec<AnyLinkableHash>>,
+{
+    if pagination.limit == 0 {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The limit of a page must be greater than 0".into()
+        )));
+    }
+    let limit = pagination.limit as usize;
+    let mut items = Vec::new();
+    let (first_bucket, mut last_target) = match &pagination.cursor {
+        Some(cursor) => (cursor.bucket as usize, Some(cursor.last_target.clone())),
+        None => (0, None),
+    };
+
+    for (bucket, anchor) in anchors.iter().enumerate().skip(first_bucket) {
+        let mut targets = get_targets(anchor)?;
+        targets.sort();
+        targets.dedup();
+        for target in targets {
+            if last_target.as_ref().is_some_and(|last| &target <= last) {
+                continue;
+            }
+            items.push(target.clone());
+            if items.len() >= limit {
+                return Ok(Page {
+                    items,
+                    next_cursor: Some(PageCursor {
+                        bucket: bucket as u32,
+                        last_target: target,
+                    }),
+                });
+            }
+        }
+        last_target = None;
+    }
+
+    Ok(Page {
+        items,
+        next_cursor: None,
+    })
+}
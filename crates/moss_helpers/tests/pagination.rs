@@ -0,0 +1,64 @@
+use hdk::prelude::*;
+
+use moss_helpers::{get_bucketed_targets_page, PaginationInput};
+
+fn target(byte: u8) -> AnyLinkableHash {
+    EntryHash::from_raw_36(vec![byte; 36]).into()
+}
+
+fn anchors() -> Vec<EntryHash> {
+    vec![
+        EntryHash::from_raw_36(vec![100; 36]),
+        EntryHash::from_raw_36(vec![101; 36]),
+    ]
+}
+
+fn get_targets(anchor: &EntryHash) -> ExternResult<Vec<AnyLinkableHash>> {
+    if anchor == &anchors()[0] {
+        Ok(vec![target(2), target(1), target(2)])
+    } else {
+        Ok(vec![target(3)])
+    }
+}
+
+#[test]
+fn pages_through_all_buckets() {
+    let anchors = anchors();
+
+    let page = get_bucketed_targets_page(
+        &anchors,
+        &PaginationInput {
+            cursor: None,
+            limit: 2,
+        },
+        get_targets,
+    )
+    .unwrap();
+    assert_eq!(page.items, vec![target(1), target(2)]);
+    assert!(page.next_cursor.is_some());
+
+    let page = get_bucketed_targets_page(
+        &anchors,
+        &PaginationInput {
+            cursor: page.next_cursor,
+            limit: 2,
+        },
+        get_targets,
+    )
+    .unwrap();
+    assert_eq!(page.items, vec![target(3)]);
+    assert!(page.next_cursor.is_none());
+}
+
+#[test]
+fn rejects_zero_limit() {
+    let result = get_bucketed_targets_page(
+        &anchors(),
+        &PaginationInput {
+            cursor: None,
+            limit: 0,
+        },
+        get_targets,
+    );
+    assert!(result.is_err());
+}
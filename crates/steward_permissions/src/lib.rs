@@ -0,0 +1,85 @@
+//! Types and validation of StewardPermissions that are shared by the integrity zomes
+//! of the group DNA. The entries are defined by the group integrity zome, other
+//! integrity zomes read them through the functions of this crate to validate actions
+//! that require steward rights.
+use hdi::prelude::*;
+
+pub mod steward_permission;
+pub use steward_permission::*;
+
+#[derive(Clone, Serialize, Deserialize, Debug, SerializedBytes)]
+pub struct GroupDnaProperties {
+    pub progenitor: Option<AgentPubKeyB64>,
+    /// If set to true, agents other than the progenitor need to provide a
+    /// GroupMembraneProof with a valid GroupInvitation in order to join
+    #[serde(default)]
+    pub invite_only: bool,
+    /// Founding council of the group. Founders have the same rights as the progenitor.
+    #[serde(default)]
+    pub founders: Vec<AgentPubKeyB64>,
+    /// Number of founders that need to approve sensitive actions like issuing
+    /// non-expiring StewardPermissions. Defaults to 1.
+    #[serde(default)]
+    pub founder_threshold: Option<u32>,
+}
+
+impl GroupDnaProperties {
+    /// All agents with progenitor rights, i.e. the progenitor and the founding council
+    pub fn founders(&self) -> Vec<AgentPubKey> {
+        let mut founders: Vec<AgentPubKey> = Vec::new();
+        for founder_b64 in self.progenitor.iter().chain(self.founders.iter()) {
+            let founder = AgentPubKey::from(founder_b64.clone());
+            if !founders.contains(&founder) {
+                founders.push(founder);
+            }
+        }
+        founders
+    }
+
+    /// If neither a progenitor nor founders are defined, everyone is treated as progenitor
+    pub fn has_founders(&self) -> bool {
+        self.progenitor.is_some() || !self.founders.is_empty()
+    }
+
+    pub fn is_founder(&self, agent: &AgentPubKey) -> bool {
+        !self.has_founders() || self.founders().contains(agent)
+    }
+
+    pub fn founder_threshold(&self) -> usize {
+        self.founder_threshold.unwrap_or(1) as usize
+    }
+}
+
+pub fn group_dna_properties() -> ExternResult<GroupDnaProperties> {
+    GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
+        wasm_error!(WasmErrorInner::Guest(format!(
+            "Failed to deserialize DNA properties: {e}"
+        )))
+    })
+}
+
+/// Name of the integrity zome that defines the entries of this crate in the group DNA
+pub const GROUP_INTEGRITY_ZOME_NAME: &str = "group_integrity";
+
+/// Positions of the entry types of this crate in the EntryTypes enum of the group
+/// integrity zome, which asserts them at compile time
+pub const STEWARD_PERMISSION_ENTRY_INDEX: u8 = 0;
+pub const STEWARD_PERMISSION_REVOCATION_RECEIPT_ENTRY_INDEX: u8 = 16;
+pub const STEWARD_DELETE_AUTHORIZATION_ENTRY_INDEX: u8 = 17;
+
+/// Whether the action creates an entry of the given type of the group integrity zome
+pub fn is_group_entry_type(action: &Action, entry_index: u8) -> ExternResult<bool> {
+    let zome_names = dna_info()?.zome_names;
+    let Some(zome_index) = zome_names
+        .iter()
+        .position(|zome_name| zome_name == &ZomeName::from(GROUP_INTEGRITY_ZOME_NAME))
+    else {
+        return Ok(false);
+    };
+    Ok(matches!(
+        action.entry_type(),
+        Some(EntryType::App(app_entry_def))
+            if app_entry_def.zome_index == ZomeIndex(zome_index as u8)
+                && app_entry_def.entry_index == EntryDefIndex(entry_index)
+    ))
+}
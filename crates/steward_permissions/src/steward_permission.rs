@@ -0,0 +1,336 @@
+use hdi::prelude::*;
+
+use crate::{
+    group_dna_properties, is_group_entry_type, STEWARD_DELETE_AUTHORIZATION_ENTRY_INDEX,
+    STEWARD_PERMISSION_ENTRY_INDEX, STEWARD_PERMISSION_REVOCATION_RECEIPT_ENTRY_INDEX,
+};
+
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct StewardPermission {
+    pub permission_hash: Option<ActionHash>,
+    pub for_agent: AgentPubKey,
+    pub expiry: Option<Timestamp>,
+    /// Capabilities granted by this permission. None grants all capabilities.
+    #[serde(default)]
+    pub scope: Option<Vec<StewardCapability>>,
+    /// Approvals of founders for non-expiring StewardPermissions in groups with a
+    /// founder threshold larger than 1
+    #[serde(default)]
+    pub founder_signatures: Vec<FounderSignature>,
+}
+
+impl StewardPermission {
+    /// The content that founders sign to approve a StewardPermission
+    pub fn signing_payload(&self) -> StewardPermission {
+        StewardPermission {
+            founder_signatures: Vec::new(),
+            ..self.clone()
+        }
+    }
+
+    pub fn has_capability(&self, capability: &StewardCapability) -> bool {
+        match &self.scope {
+            None => true,
+            Some(capabilities) => capabilities.contains(capability),
+        }
+    }
+
+    /// Whether all capabilities of the given scope are granted by this permission
+    pub fn covers_scope(&self, scope: &Option<Vec<StewardCapability>>) -> bool {
+        match (&self.scope, scope) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(_), Some(capabilities)) => capabilities.iter().all(|c| self.has_capability(c)),
+        }
+    }
+}
+
+/// The actions that a StewardPermission can grant
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type", content = "content")]
+pub enum StewardCapability {
+    ManageApplets,
+    EditProfile,
+    ManageStewards,
+    InviteMembers,
+    /// Write access to the GroupMetaData with the given name
+    WriteMetaData(String),
+    /// Update and delete custom views of other agents and set their active versions
+    ManageCustomViews,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct FounderSignature {
+    pub founder: AgentPubKey,
+    pub signature: Signature,
+}
+
+/// Acknowledges a StewardPermissionRevocation on the source chain of an agent whose
+/// StewardPermission, or one of the permissions it has been issued under, got revoked.
+/// Validation can only check source chains deterministically up to actions that it
+/// knows of, which for an action taken based on a StewardPermission is the source
+/// chain of its author up to the action. Revocations are therefore enforced in
+/// validation once they have been acknowledged by the revoked agent and on the read
+/// paths from the revocation onward.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct StewardPermissionRevocationReceipt {
+    /// ActionHash of the StewardPermissionRevocation that is acknowledged
+    pub revocation_hash: ActionHash,
+    /// ActionHash of the StewardPermission that has been revoked
+    pub revoked_permission_hash: ActionHash,
+}
+
+/// References the StewardPermission based on which the next action of its author
+/// deletes an entry or a link created by another agent. Deletes cannot reference a
+/// StewardPermission themselves, so stewards commit a StewardDeleteAuthorization
+/// right before deleting.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct StewardDeleteAuthorization {
+    /// ActionHash of the StewardPermission of the author
+    pub permission_hash: ActionHash,
+    /// ActionHash of the create action of the entry or link that gets deleted
+    pub deleted_action_hash: ActionHash,
+}
+
+/// Rules
+/// 1. The progenitor and founders can take any action. Without progenitor and founders
+///    everyone can.
+/// 2. Everyone else needs a StewardPermission for themselves that grants the required
+///    capability and has not expired
+/// 3. Neither the StewardPermission nor any of the permissions it has been issued under
+///    may have been revoked (see validate_steward_permission_not_revoked)
+pub fn validate_steward_permission(
+    agent: &AgentPubKey,
+    prev_action: &ActionHash,
+    permission_hash: Option<ActionHash>,
+    timestamp: &Timestamp,
+    allow_expiring_permissions: bool,
+    required_capability: StewardCapability,
+) -> ExternResult<ValidateCallbackResult> {
+    let result = validate_steward_permission_grant(
+        agent,
+        permission_hash.clone(),
+        timestamp,
+        allow_expiring_permissions,
+        required_capability,
+    )?;
+    if let ValidateCallbackResult::Invalid(_) = result {
+        return Ok(result);
+    }
+    match permission_hash {
+        Some(permission_hash) if !group_dna_properties()?.is_founder(agent) => {
+            validate_steward_permission_not_revoked(agent, prev_action, &permission_hash, timestamp)
+        }
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+/// Validates rules 1. and 2. of validate_steward_permission, i.e. everything but
+/// revocations
+pub fn validate_steward_permission_grant(
+    agent: &AgentPubKey,
+    permission_hash: Option<ActionHash>,
+    timestamp: &Timestamp,
+    allow_expiring_permissions: bool,
+    required_capability: StewardCapability,
+) -> ExternResult<ValidateCallbackResult> {
+    let dna_properties = group_dna_properties()?;
+
+    match dna_properties.has_founders() {
+        true => {
+            if dna_properties.is_founder(agent) {
+                Ok(ValidateCallbackResult::Valid)
+            } else {
+                match permission_hash {
+                    Some(ph) => {
+                        // get permission record
+                        let permission_record = must_get_valid_record(ph.clone())?;
+                        if !is_group_entry_type(
+                            permission_record.action(),
+                            STEWARD_PERMISSION_ENTRY_INDEX,
+                        )? {
+                            return Ok(ValidateCallbackResult::Invalid(
+                                "permission hash does not point to a StewardPermission entry"
+                                    .into(),
+                            ));
+                        }
+
+                        let maybe_steward_permission = match permission_record
+                            .entry()
+                            .to_app_option::<StewardPermission>()
+                        {
+                            Ok(perm) => perm,
+                            Err(_) => return Ok(ValidateCallbackResult::Invalid(
+                                "permission hash does not point to a serializable StewardPermission entry"
+                                    .into(),
+                            )),
+                        };
+
+                        let steward_permission = match maybe_steward_permission {
+                            Some(perm) => perm,
+                            None => return Ok(ValidateCallbackResult::Invalid(
+                                "permission hash does not point to a valid StewardPermission entry"
+                                    .into(),
+                            )),
+                        };
+
+                        // 1. Validate that permission is for the correct agent
+                        if &steward_permission.for_agent != agent {
+                            return Ok(ValidateCallbackResult::Invalid(
+                                "StewardPermission is for the wrong agent.".into(),
+                            ));
+                        }
+
+                        // 2. Validate that the permission grants the capability required for
+                        //    the given action
+                        if !steward_permission.has_capability(&required_capability) {
+                            return Ok(ValidateCallbackResult::Invalid(
+                                "StewardPermission does not grant the capability required for this action."
+                                    .into(),
+                            ));
+                        }
+
+                        // 3. If the StewardPermission is has an expiry check whether expiring
+                        //    permissions are allowed to take the given action
+                        if let Some(_) = steward_permission.expiry {
+                            if allow_expiring_permissions == false {
+                                return Ok(ValidateCallbackResult::Invalid(
+                                    "Only non-expiring StewardPermissions are allowed to take this action."
+                                        .into(),
+                                ));
+                            }
+                        }
+
+                        // 4. Validate that the permission has not expired
+                        if let Some(expiry) = steward_permission.expiry {
+                            if &expiry < timestamp {
+                                return Ok(ValidateCallbackResult::Invalid(
+                                    "StewardPermission has expired.".into(),
+                                ));
+                            }
+                        }
+
+                        Ok(ValidateCallbackResult::Valid)
+                    }
+                    None => Ok(ValidateCallbackResult::Invalid(
+                        "No valid permission hash provided and agent is not the progenitor".into(),
+                    )),
+                }
+            }
+        }
+        false => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+/// Rules
+/// 1. The source chain of the agent up to the given previous action must not contain a
+///    StewardPermissionRevocationReceipt for the permission or any of the permissions
+///    it has been issued under
+///
+/// Only the source chain of the acting agent is known to validation up to the action,
+/// so revocations that the agent has not acknowledged yet are enforced on the read
+/// paths instead, see is_agent_a_steward in the coordinator zome.
+pub fn validate_steward_permission_not_revoked(
+    agent: &AgentPubKey,
+    prev_action: &ActionHash,
+    permission_hash: &ActionHash,
+    timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(permission_chain) = steward_permission_chain(permission_hash.clone())? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "permission hash does not point to a StewardPermission entry".into(),
+        ));
+    };
+    let permission_hashes: Vec<ActionHash> = permission_chain
+        .iter()
+        .map(|record| record.action_address().clone())
+        .collect();
+    // Revocations cannot be acknowledged before the permissions they revoke exist
+    let earliest_timestamp = permission_chain
+        .last()
+        .map(|record| record.action().timestamp())
+        .unwrap_or(*timestamp);
+
+    let activity = must_get_agent_activity(
+        agent.clone(),
+        ChainFilter::new(prev_action.clone()).until_timestamp(earliest_timestamp),
+    )?;
+    for item in activity {
+        let action = &item.action.hashed.content;
+        if !is_group_entry_type(action, STEWARD_PERMISSION_REVOCATION_RECEIPT_ENTRY_INDEX)?
+            || &action.timestamp() > timestamp
+        {
+            continue;
+        }
+        let record = must_get_valid_record(item.action.hashed.hash.clone())?;
+        let Ok(receipt) = StewardPermissionRevocationReceipt::try_from(record) else {
+            continue;
+        };
+        if permission_hashes.contains(&receipt.revoked_permission_hash) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "StewardPermission has been revoked.".into(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// The chain of StewardPermissions that a StewardPermission has been issued under,
+/// starting with the permission itself and ending with the one issued by the
+/// progenitor or a founder. None if any of the records is not a StewardPermission.
+pub fn steward_permission_chain(permission_hash: ActionHash) -> ExternResult<Option<Vec<Record>>> {
+    let dna_properties = group_dna_properties()?;
+    let mut chain: Vec<Record> = Vec::new();
+    let mut next_permission_hash = Some(permission_hash);
+    while let Some(permission_hash) = next_permission_hash {
+        let record = must_get_valid_record(permission_hash)?;
+        if !is_group_entry_type(record.action(), STEWARD_PERMISSION_ENTRY_INDEX)? {
+            return Ok(None);
+        }
+        let Ok(steward_permission) = StewardPermission::try_from(record.clone()) else {
+            return Ok(None);
+        };
+        next_permission_hash = match dna_properties.is_founder(record.action().author()) {
+            true => None,
+            false => steward_permission.permission_hash,
+        };
+        chain.push(record);
+    }
+    Ok(Some(chain))
+}
+
+/// Validates that the agent is the progenitor, a founder or a steward for deletes,
+/// which cannot reference a StewardPermission. The StewardPermission is taken from
+/// the StewardDeleteAuthorization for the deleted action that the agent committed
+/// right before the delete.
+pub fn validate_steward_without_permission_hash(
+    agent: &AgentPubKey,
+    prev_action: &ActionHash,
+    deleted_action_hash: &ActionHash,
+    timestamp: &Timestamp,
+    allow_expiring_permissions: bool,
+    required_capability: StewardCapability,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(prev_action.clone())?;
+    let permission_hash =
+        match is_group_entry_type(record.action(), STEWARD_DELETE_AUTHORIZATION_ENTRY_INDEX)? {
+            true => match StewardDeleteAuthorization::try_from(record) {
+                Ok(authorization) if &authorization.deleted_action_hash == deleted_action_hash => {
+                    Some(authorization.permission_hash)
+                }
+                _ => None,
+            },
+            false => None,
+        };
+    validate_steward_permission(
+        agent,
+        prev_action,
+        permission_hash,
+        timestamp,
+        allow_expiring_permissions,
+        required_capability,
+    )
+}
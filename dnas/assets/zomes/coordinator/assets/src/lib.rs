@@ -1,5 +1,6 @@
 pub mod associations;
 pub mod relations;
+pub mod removals;
 use assets_integrity::*;
 use hdk::prelude::*;
 use relations::{AssetRelationAndHash, AssetRelationWithTags};
@@ -3,9 +3,17 @@ use core::str;
 use assets_integrity::*;
 use hdk::prelude::*;
 use itertools::Itertools;
-use moss_helpers::ZomeFnInput;
-
-use crate::{associations::get_tags_for_asset, Signal};
+use moss_helpers::{get_bucketed_targets_page, Page, PaginationInput, ZomeFnInput};
+use std::collections::HashMap;
+
+use crate::{
+    associations::get_tags_for_asset,
+    removals::{
+        filter_removed_by_stewards, get_delegations_to_me, my_steward_permission_hash,
+        remove_asset_relation_link,
+    },
+    Signal,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AssetRelationAndHash {
@@ -21,7 +29,25 @@ pub struct AssetRelationWithTags {
     pub dst_wal: WAL,
     pub tags: Vec<String>,
     pub relation_hash: EntryHash,
+    /// Timestamp of the first creation of the AssetRelation entry
     pub created_at: Timestamp,
+    /// Agents that have asserted the asset relation, earliest first
+    pub endorsements: Vec<Endorsement>,
+    /// Agents that have added each of the tags, earliest first
+    pub tag_endorsements: Vec<TagEndorsements>,
+}
+
+/// An agent that has asserted an asset relation or added a relationship tag to it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Endorsement {
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TagEndorsements {
+    pub tag: String,
+    pub endorsements: Vec<Endorsement>,
 }
 
 #[derive(Serialize, Deserialize, SerializedBytes, Debug)]
@@ -49,7 +75,7 @@ pub fn add_asset_relation(
         Ok(Some(r)) => r,
         _ => {
             let action_hash = create_entry(&EntryTypes::AssetRelation(asset_relation.clone()))?;
-            let path = Path::from(ALL_ASSET_RELATIONS_ANCHOR);
+            let path = all_asset_relations_bucket_anchor(&relation_hash);
             create_link(
                 path.path_entry_hash()?,
                 relation_hash.clone(),
@@ -87,13 +113,16 @@ pub fn add_asset_relation(
         (),
     )?;
 
-    let asset_relation_with_tags = AssetRelationWithTags {
-        src_wal: input.input.src_wal,
-        dst_wal: input.input.dst_wal,
-        tags: input.input.tags,
-        relation_hash,
-        created_at: record.action().timestamp(),
-    };
+    let asset_relation_with_tags = get_tags_and_endorsements_for_relations(
+        vec![AssetRelationAndHash {
+            src_wal: input.input.src_wal,
+            dst_wal: input.input.dst_wal,
+            relation_hash,
+            created_at: record.action().timestamp(),
+        }],
+        input.get_strategy(),
+    )?
+    .remove(0);
 
     emit_signal(Signal::AssetRelationCreated {
         relation: asset_relation_with_tags.clone(),
@@ -169,8 +198,20 @@ pub fn add_tags_to_asset_relation(
     Ok(())
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoveAssetRelationInput {
+    pub relation_hash: EntryHash,
+    pub reason: Option<String>,
+}
+
+/// Removes an asset relation and records an AssetRelationTombstone with the reason
+/// for the removal. Only the links created by the calling agent or by agents that
+/// have delegated the removal of their asset relations to the calling agent are
+/// deleted. If the calling agent is a steward of the group, the tombstone hides the
+/// asset relation for everyone irrespective of the links that remain.
 #[hdk_extern]
-pub fn remove_asset_relation(relation_hash: ZomeFnInput<EntryHash>) -> ExternResult<()> {
+pub fn remove_asset_relation(input: ZomeFnInput<RemoveAssetRelationInput>) -> ExternResult<()> {
+    let relation_hash = ZomeFnInput::new(input.input.relation_hash.clone(), input.local);
     let asset_relation_record = get(relation_hash.input.clone(), relation_hash.get_options())?
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "No AssetRelation entry found for the provided hash.".into()
@@ -196,40 +237,83 @@ pub fn remove_asset_relation(relation_hash: ZomeFnInput<EntryHash>) -> ExternRes
     // to delete it. We would need to store it somewhere, for example in the tag
     // of the link to the AllAssetRelations anchor.
 
-    // 1. remove all links from the ALL_ASSETS_RELATIONS_ANCHOR
-    let path = Path::from(ALL_ASSET_RELATIONS_ANCHOR);
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllAssetRelations)?, relation_hash.get_strategy())?;
-    for link in links {
-        if let Some(target) = link.target.into_entry_hash() {
-            if target.eq(&relation_hash.input) {
-                delete_link(link.create_link_hash, GetOptions::local())?;
-            }
-        }
+    // 1. Collect all links that make up the asset relation
+    let mut relation_links: Vec<Link> = Vec::new();
+    for path in [
+        Path::from(ALL_ASSET_RELATIONS_ANCHOR),
+        all_asset_relations_bucket_anchor(&relation_hash.input),
+    ] {
+        relation_links.extend(get_links(
+            LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllAssetRelations)?,
+            relation_hash.get_strategy(),
+        )?);
     }
+    relation_links.extend(get_links(
+        LinkQuery::try_new(
+            hash_entry(asset_relation.src_wal.clone())?,
+            LinkTypes::SrcWalToAssetRelations,
+        )?,
+        relation_hash.get_strategy(),
+    )?);
+    relation_links.extend(get_links(
+        LinkQuery::try_new(
+            hash_entry(asset_relation.dst_wal.clone())?,
+            LinkTypes::DstWalToAssetRelations,
+        )?,
+        relation_hash.get_strategy(),
+    )?);
+    relation_links.retain(|link| link.target == relation_hash.input.clone().into());
+    let tag_links = get_links(
+        LinkQuery::try_new(
+            relation_hash.input.clone(),
+            LinkTypes::AssetRelationToRelationshipTags,
+        )?,
+        relation_hash.get_strategy(),
+    )?;
 
-    // 2. remove all tags
-    remove_all_tags_from_asset_relation(relation_hash.clone())?;
-
-    // 3. Remove all links from the source WAL
-    let src_wal_entry_hash = hash_entry(asset_relation.src_wal.clone())?;
-    let src_wal_links = get_links(
-        LinkQuery::try_new(src_wal_entry_hash, LinkTypes::SrcWalToAssetRelations)?, relation_hash.get_strategy())?;
-    for link in src_wal_links {
-        if link.target.clone().into_hash() == relation_hash.input.clone().into() {
-            delete_link(link.create_link_hash, GetOptions::local())?;
-        }
+    // 2. Record the tombstone, referencing the delegations of the creators of the
+    //    links to the calling agent and the StewardPermission of the calling agent
+    //    if they are a steward. The deletes of the links of other agents reference
+    //    the tombstone.
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let other_authors: Vec<AgentPubKey> = relation_links
+        .iter()
+        .chain(tag_links.iter())
+        .map(|link| link.author.clone())
+        .filter(|author| author != &my_pub_key)
+        .unique()
+        .collect();
+    let delegations = get_delegations_to_me(&other_authors)?;
+    let mut removable_authors = vec![my_pub_key];
+    let mut delegation_hashes = Vec::new();
+    for (delegator, delegation_hash) in delegations {
+        removable_authors.push(delegator);
+        delegation_hashes.push(delegation_hash);
     }
+    let tombstone_hash = create_entry(&EntryTypes::AssetRelationTombstone(
+        AssetRelationTombstone {
+            relation_hash: relation_hash.input.clone(),
+            reason: input.input.reason,
+            delegation_hashes,
+            steward_permission_hash: my_steward_permission_hash()?,
+        },
+    ))?;
+    create_link(
+        relation_hash.input.clone(),
+        tombstone_hash.clone(),
+        LinkTypes::AssetRelationToTombstones,
+        (),
+    )?;
 
-    // 4. Remove all links from the destination WAL
-    let dst_wal_entry_hash = hash_entry(asset_relation.dst_wal.clone())?;
-    let dst_wal_links = get_links(
-        LinkQuery::try_new(dst_wal_entry_hash, LinkTypes::DstWalToAssetRelations)?, relation_hash.get_strategy())?;
-    for link in dst_wal_links {
-        if link.target.clone().into_hash() == relation_hash.input.clone().into() {
-            delete_link(link.create_link_hash, GetOptions::local())?;
+    // 3. Remove the links from the anchors and the WALs as well as the tags
+    for link in relation_links {
+        if removable_authors.contains(&link.author) {
+            remove_asset_relation_link(link.create_link_hash, &link.author, &tombstone_hash)?;
         }
     }
+    remove_tag_links(tag_links, Some(&tombstone_hash), |link, _| {
+        removable_authors.contains(&link.author)
+    })?;
 
     emit_signal(Signal::AssetRelationRemoved {
         relation: AssetRelationAndHash {
@@ -243,25 +327,44 @@ pub fn remove_asset_relation(relation_hash: ZomeFnInput<EntryHash>) -> ExternRes
     Ok(())
 }
 
-pub fn remove_all_tags_from_asset_relation(
-    relation_hash: ZomeFnInput<EntryHash>,
+/// Deletes the given AssetRelationToRelationshipTags links for which the predicate
+/// holds, together with their RelationshipTagToAssetRelation backlinks. Links of other
+/// agents can only be deleted based on an AssetRelationTombstone.
+fn remove_tag_links(
+    links: Vec<Link>,
+    tombstone_hash: Option<&ActionHash>,
+    predicate: impl Fn(&Link, &LinkTagContent) -> bool,
 ) -> ExternResult<()> {
-    let links = get_links(
-        LinkQuery::try_new(
-            relation_hash.input.clone(),
-            LinkTypes::AssetRelationToRelationshipTags,
-        )?, relation_hash.get_strategy()
-    )?;
     for link in links {
-        let link_tag_content = ExternIO::from(link.tag.0)
-            .decode::<LinkTagContent>()
-            .map_err(|e| {
-                wasm_error!(WasmErrorInner::Guest(format!(
-                    "Failed to decode link tag content: {e}"
-                )))
-            })?;
-        delete_link(link.create_link_hash, GetOptions::local())?;
-        delete_link(link_tag_content.backlink_action_hash, GetOptions::local())?;
+        match ExternIO::from(link.tag.0.clone()).decode::<LinkTagContent>() {
+            Ok(link_tag_content) => {
+                if !predicate(&link, &link_tag_content) {
+                    continue;
+                }
+                match tombstone_hash {
+                    Some(tombstone_hash) => {
+                        // The backlink is created together with the link, by the same agent
+                        remove_asset_relation_link(
+                            link.create_link_hash,
+                            &link.author,
+                            tombstone_hash,
+                        )?;
+                        remove_asset_relation_link(
+                            link_tag_content.backlink_action_hash,
+                            &link.author,
+                            tombstone_hash,
+                        )?;
+                    }
+                    None => {
+                        delete_link(link.create_link_hash, GetOptions::local())?;
+                        delete_link(link_tag_content.backlink_action_hash, GetOptions::local())?;
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("WARNING: Failed to decode link tag content of AssetRelationToRelationshipTags link: {e}");
+            }
+        }
     }
     Ok(())
 }
@@ -272,6 +375,8 @@ pub struct RemoveTagsFromAssetRelationInput {
     pub tags: Vec<String>,
 }
 
+/// Removes tags from an asset relation. Only the tags added by the calling agent
+/// are removed.
 #[hdk_extern]
 pub fn remove_tags_from_asset_relation(
     input: ZomeFnInput<RemoveTagsFromAssetRelationInput>,
@@ -302,25 +407,10 @@ pub fn remove_tags_from_asset_relation(
             LinkTypes::AssetRelationToRelationshipTags,
         )?, input.get_strategy()
     )?;
-    for link in links {
-        match ExternIO::from(link.tag.0)
-            .decode::<LinkTagContent>()
-            .map_err(|e| {
-                wasm_error!(WasmErrorInner::Guest(format!(
-                    "Failed to decode link tag content: {e}"
-                )))
-            }) {
-            Ok(link_tag_content) => {
-                if input.input.tags.contains(&link_tag_content.tag) {
-                    delete_link(link.create_link_hash, GetOptions::local())?;
-                    delete_link(link_tag_content.backlink_action_hash, GetOptions::local())?;
-                }
-            }
-            Err(e) => {
-                eprintln!("WARNING: Failed to retrieve String from link tag in zome 'remove_tags_from_asset_relation': {e}", );
-            }
-        }
-    }
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    remove_tag_links(links, None, |link, link_tag_content| {
+        link.author == my_pub_key && input.input.tags.contains(&link_tag_content.tag)
+    })?;
     emit_signal(Signal::RelationTagsRemoved {
         relation_hash: input.input.relation_hash,
         src_wal: asset_relation.src_wal,
@@ -340,32 +430,216 @@ pub fn get_asset_relation_by_hash(
     }
 }
 
+/// Gets an asset relation together with its tags and the agents that have asserted
+/// it or added its tags
 #[hdk_extern]
-pub fn get_all_asset_relation_hashes(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>> {
-    let path = Path::from(ALL_ASSET_RELATIONS_ANCHOR);
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllAssetRelations)?,
-        input.get_strategy(),
+pub fn get_asset_relation_with_tags(
+    relation_hash: ZomeFnInput<EntryHash>,
+) -> ExternResult<Option<AssetRelationWithTags>> {
+    let asset_relations = get_asset_relations_by_hashes(
+        vec![relation_hash.input.clone()],
+        relation_hash.get_options(),
+    )?;
+    Ok(
+        get_tags_and_endorsements_for_relations(asset_relations, relation_hash.get_strategy())?
+            .into_iter()
+            .next(),
+    )
+}
+
+/// Adds the tags and endorsements to each of the asset relations, see
+/// get_tags_and_endorsements. The SrcWalToAssetRelations links are fetched only once
+/// for all asset relations with the same source WAL.
+fn get_tags_and_endorsements_for_relations(
+    asset_relations: Vec<AssetRelationAndHash>,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<AssetRelationWithTags>> {
+    let mut links_by_src_wal: HashMap<EntryHash, Vec<Link>> = HashMap::new();
+    let mut relations_with_tags = Vec::new();
+    for asset_relation in asset_relations {
+        let src_wal_hash = hash_entry(asset_relation.src_wal.clone())?;
+        if !links_by_src_wal.contains_key(&src_wal_hash) {
+            let src_wal_links = get_links(
+                LinkQuery::try_new(src_wal_hash.clone(), LinkTypes::SrcWalToAssetRelations)?,
+                strategy,
+            )?;
+            links_by_src_wal.insert(src_wal_hash.clone(), src_wal_links);
+        }
+        relations_with_tags.push(get_tags_and_endorsements(
+            asset_relation,
+            &links_by_src_wal[&src_wal_hash],
+            strategy,
+        )?);
+    }
+    Ok(relations_with_tags)
+}
+
+/// Adds the tags of an asset relation as well as the endorsements of the asset
+/// relation and its tags. Since the AssetRelation entry is shared by everyone that
+/// asserts the same relation, the endorsements are derived from the
+/// SrcWalToAssetRelations links of its source WAL and the
+/// AssetRelationToRelationshipTags links that every agent creates for themselves.
+fn get_tags_and_endorsements(
+    asset_relation: AssetRelationAndHash,
+    src_wal_links: &[Link],
+    strategy: GetStrategy,
+) -> ExternResult<AssetRelationWithTags> {
+    let relation_target: AnyLinkableHash = asset_relation.relation_hash.clone().into();
+    let endorsements = endorsements_from_links(
+        src_wal_links
+            .iter()
+            .filter(|link| link.target == relation_target),
+    );
+
+    let tag_links = get_links(
+        LinkQuery::try_new(
+            asset_relation.relation_hash.clone(),
+            LinkTypes::AssetRelationToRelationshipTags,
+        )?,
+        strategy,
     )?;
-    Ok(links
+    let links_and_tags: Vec<(Link, String)> = tag_links
         .into_iter()
-        .filter_map(|l| l.target.into_entry_hash())
-        .collect())
+        .filter_map(|link| {
+            ExternIO::from(link.tag.0.clone())
+                .decode::<LinkTagContent>()
+                .ok()
+                .map(|content| (link, content.tag))
+        })
+        .collect();
+    let tags = links_and_tags
+        .iter()
+        .map(|(_, tag)| tag.clone())
+        .unique()
+        .collect();
+    let tag_endorsements = links_and_tags
+        .iter()
+        .map(|(_, tag)| tag)
+        .unique()
+        .map(|tag| TagEndorsements {
+            tag: tag.clone(),
+            endorsements: endorsements_from_links(
+                links_and_tags
+                    .iter()
+                    .filter(|(_, t)| t == tag)
+                    .map(|(link, _)| link),
+            ),
+        })
+        .collect();
+
+    Ok(AssetRelationWithTags {
+        src_wal: asset_relation.src_wal,
+        dst_wal: asset_relation.dst_wal,
+        tags,
+        relation_hash: asset_relation.relation_hash,
+        created_at: asset_relation.created_at,
+        endorsements,
+        tag_endorsements,
+    })
+}
+
+/// One Endorsement per author of the links with the timestamp of their earliest
+/// link, ordered by timestamp
+fn endorsements_from_links<'a>(links: impl Iterator<Item = &'a Link>) -> Vec<Endorsement> {
+    let mut endorsements: Vec<Endorsement> = Vec::new();
+    for link in links {
+        match endorsements.iter_mut().find(|e| e.author == link.author) {
+            Some(endorsement) => {
+                if link.timestamp < endorsement.timestamp {
+                    endorsement.timestamp = link.timestamp;
+                }
+            }
+            None => endorsements.push(Endorsement {
+                author: link.author.clone(),
+                timestamp: link.timestamp,
+            }),
+        }
+    }
+    endorsements.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.author.cmp(&b.author))
+    });
+    endorsements
+}
+
+/// The anchors from which asset relations get linked, with the legacy
+/// ALL_ASSET_RELATIONS_ANCHOR last
+fn all_asset_relations_anchors() -> ExternResult<Vec<EntryHash>> {
+    let mut anchors = (0..ALL_ASSET_RELATIONS_BUCKETS)
+        .map(|bucket| all_asset_relations_bucket_anchor_by_index(bucket).path_entry_hash())
+        .collect::<ExternResult<Vec<EntryHash>>>()?;
+    anchors.push(Path::from(ALL_ASSET_RELATIONS_ANCHOR).path_entry_hash()?);
+    Ok(anchors)
+}
+
+fn get_all_asset_relations_targets(
+    anchor: &EntryHash,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<AnyLinkableHash>> {
+    let links = get_links(
+        LinkQuery::try_new(anchor.clone(), LinkTypes::AllAssetRelations)?,
+        strategy,
+    )?;
+    Ok(links.into_iter().map(|l| l.target).collect())
+}
+
+#[hdk_extern]
+pub fn get_all_asset_relation_hashes(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>> {
+    let mut relation_hashes = Vec::new();
+    for anchor in all_asset_relations_anchors()? {
+        relation_hashes.extend(
+            get_all_asset_relations_targets(&anchor, input.get_strategy())?
+                .into_iter()
+                .filter_map(|target| target.into_entry_hash()),
+        );
+    }
+    filter_removed_by_stewards(
+        relation_hashes.into_iter().unique().collect(),
+        |relation_hash| relation_hash,
+        input.get_strategy(),
+    )
 }
 
 #[hdk_extern]
 pub fn get_all_asset_relations(input: ZomeFnInput<()>) -> ExternResult<Vec<AssetRelationAndHash>> {
-    let path = Path::from(ALL_ASSET_RELATIONS_ANCHOR);
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllAssetRelations)?
-            , input.get_strategy()
+    let relation_hashes = get_all_asset_relation_hashes(input.clone())?;
+    get_asset_relations_by_hashes(relation_hashes, input.get_options())
+}
+
+/// Paginated version of get_all_asset_relations
+#[hdk_extern]
+pub fn get_all_asset_relations_page(
+    input: ZomeFnInput<PaginationInput>,
+) -> ExternResult<Page<AssetRelationAndHash>> {
+    let page =
+        get_bucketed_targets_page(&all_asset_relations_anchors()?, &input.input, |anchor| {
+            get_all_asset_relations_targets(anchor, input.get_strategy())
+        })?;
+    let relation_hashes = page
+        .items
+        .into_iter()
+        .filter_map(|target| target.into_entry_hash())
+        .collect();
+    let relation_hashes = filter_removed_by_stewards(
+        relation_hashes,
+        |relation_hash| relation_hash,
+        input.get_strategy(),
     )?;
+    Ok(Page {
+        items: get_asset_relations_by_hashes(relation_hashes, input.get_options())?,
+        next_cursor: page.next_cursor,
+    })
+}
 
-    let get_input: Vec<GetInput> = links
+fn get_asset_relations_by_hashes(
+    relation_hashes: Vec<EntryHash>,
+    get_options: GetOptions,
+) -> ExternResult<Vec<AssetRelationAndHash>> {
+    let get_input: Vec<GetInput> = relation_hashes
         .into_iter()
-        .filter_map(|l| l.target.into_entry_hash())
-        .map(|target| Ok(GetInput::new(target.into(), input.get_options())))
-        .collect::<ExternResult<Vec<GetInput>>>()?;
+        .map(|target| GetInput::new(target.into(), get_options.clone()))
+        .collect();
 
     let records: Vec<Option<Record>> = HDK.with(|hdk| hdk.borrow().get(get_input))?;
 
@@ -397,68 +671,20 @@ pub fn get_all_asset_relations(input: ZomeFnInput<()>) -> ExternResult<Vec<Asset
 pub fn get_all_asset_relations_with_tags(
     input: ZomeFnInput<()>,
 ) -> ExternResult<Vec<AssetRelationWithTags>> {
-    let asset_relations = get_all_asset_relations(input.clone())?;
-    let mut asset_relations_with_tags: Vec<AssetRelationWithTags> = Vec::new();
-    for asset_relation in asset_relations {
-        let links = get_links(
-            LinkQuery::try_new(
-                asset_relation.relation_hash.clone(),
-                LinkTypes::AssetRelationToRelationshipTags,
-            )?, input.get_strategy()
-        )?;
-        let tags = links
-            .iter()
-            .map(|l| {
-                ExternIO::from(l.clone().tag.0)
-                    .decode::<LinkTagContent>()
-                    .ok()
-            })
-            .filter_map(|c| c)
-            .map(|c| c.tag)
-            .collect::<Vec<String>>();
-        asset_relations_with_tags.push(AssetRelationWithTags {
-            src_wal: asset_relation.src_wal,
-            dst_wal: asset_relation.dst_wal,
-            tags,
-            relation_hash: asset_relation.relation_hash,
-            created_at: asset_relation.created_at,
-        });
-    }
-    Ok(asset_relations_with_tags)
+    get_tags_and_endorsements_for_relations(
+        get_all_asset_relations(input.clone())?,
+        input.get_strategy(),
+    )
 }
 
 #[hdk_extern]
 pub fn get_outgoing_asset_relations_with_tags(
     src_wal: ZomeFnInput<WAL>,
 ) -> ExternResult<Vec<AssetRelationWithTags>> {
-    let asset_relations = get_outgoing_asset_relations(src_wal.clone())?;
-    let mut asset_relations_with_tags: Vec<AssetRelationWithTags> = Vec::new();
-    for asset_relation in asset_relations {
-        let links = get_links(
-            LinkQuery::try_new(
-                asset_relation.relation_hash.clone(),
-                LinkTypes::AssetRelationToRelationshipTags,
-            )?, src_wal.get_strategy()
-        )?;
-        let tags = links
-            .iter()
-            .map(|l| {
-                ExternIO::from(l.clone().tag.0)
-                    .decode::<LinkTagContent>()
-                    .ok()
-            })
-            .filter_map(|c| c)
-            .map(|c| c.tag)
-            .collect::<Vec<String>>();
-        asset_relations_with_tags.push(AssetRelationWithTags {
-            src_wal: asset_relation.src_wal,
-            dst_wal: asset_relation.dst_wal,
-            tags,
-            relation_hash: asset_relation.relation_hash,
-            created_at: asset_relation.created_at,
-        });
-    }
-    Ok(asset_relations_with_tags)
+    get_tags_and_endorsements_for_relations(
+        get_outgoing_asset_relations(src_wal.clone())?,
+        src_wal.get_strategy(),
+    )
 }
 
 #[hdk_extern]
@@ -506,41 +732,21 @@ pub fn get_outgoing_asset_relations(
             }
         }
     }
-    Ok(asset_relations)
+    filter_removed_by_stewards(
+        asset_relations,
+        |asset_relation| &asset_relation.relation_hash,
+        src_wal.get_strategy(),
+    )
 }
 
 #[hdk_extern]
 pub fn get_incoming_asset_relations_with_tags(
     dst_wal: ZomeFnInput<WAL>,
 ) -> ExternResult<Vec<AssetRelationWithTags>> {
-    let asset_relations = get_incoming_asset_relations(dst_wal.clone())?;
-    let mut asset_relations_with_tags: Vec<AssetRelationWithTags> = Vec::new();
-    for asset_relation in asset_relations {
-        let links = get_links(
-            LinkQuery::try_new(
-                asset_relation.relation_hash.clone(),
-                LinkTypes::AssetRelationToRelationshipTags,
-            )?, dst_wal.get_strategy()
-        )?;
-        let tags = links
-            .iter()
-            .map(|l| {
-                ExternIO::from(l.clone().tag.0)
-                    .decode::<LinkTagContent>()
-                    .ok()
-            })
-            .filter_map(|c| c)
-            .map(|c| c.tag)
-            .collect::<Vec<String>>();
-        asset_relations_with_tags.push(AssetRelationWithTags {
-            src_wal: asset_relation.src_wal,
-            dst_wal: asset_relation.dst_wal,
-            tags,
-            relation_hash: asset_relation.relation_hash,
-            created_at: asset_relation.created_at,
-        });
-    }
-    Ok(asset_relations_with_tags)
+    get_tags_and_endorsements_for_relations(
+        get_incoming_asset_relations(dst_wal.clone())?,
+        dst_wal.get_strategy(),
+    )
 }
 
 #[hdk_extern]
@@ -588,7 +794,11 @@ pub fn get_incoming_asset_relations(
             }
         }
     }
-    Ok(asset_relations)
+    filter_removed_by_stewards(
+        asset_relations,
+        |asset_relation| &asset_relation.relation_hash,
+        dst_wal.get_strategy(),
+    )
 }
 
 #[hdk_extern]
@@ -644,7 +854,11 @@ pub fn get_asset_relations_for_relationship_tag(
             }
         }
     }
-    Ok(asset_relations)
+    filter_removed_by_stewards(
+        asset_relations,
+        |asset_relation| &asset_relation.relation_hash,
+        tag.get_strategy(),
+    )
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
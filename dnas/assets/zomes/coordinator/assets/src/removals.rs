@@ -0,0 +1,340 @@
+use assets_integrity::*;
+use hdk::prelude::*;
+use moss_helpers::ZomeFnInput;
+use serde::de::IgnoredAny;
+use std::collections::HashMap;
+
+/// Mirror of the Accountability type of the group zome. Only the parts needed to
+/// tell stewards apart from other members are decoded.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", content = "content")]
+enum GroupAccountability {
+    Progenitor,
+    Steward(GroupStewardPermissionClaim),
+    Member,
+}
+
+#[derive(Deserialize, Debug)]
+struct GroupStewardPermissionClaim {
+    permission_hash: ActionHash,
+    permission: GroupStewardPermission,
+}
+
+#[derive(Deserialize, Debug)]
+struct GroupStewardPermission {
+    #[serde(default)]
+    scope: Option<IgnoredAny>,
+}
+
+/// Calls a function of the group zome that returns accountabilities. The stewards of
+/// a group are defined in the group DNA, so they are determined by calling into the
+/// group cell of the same app. If there is no group cell, there are none.
+fn call_group_accountabilities<I: Serialize + std::fmt::Debug>(
+    fn_name: &str,
+    input: I,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<GroupAccountability>> {
+    let response = call(
+        CallTargetCell::OtherRole("group".into()),
+        ZomeName::from("group"),
+        FunctionName::from(fn_name),
+        None,
+        ZomeFnInput::new(input, Some(matches!(strategy, GetStrategy::Local))),
+    )?;
+    let ZomeCallResponse::Ok(result) = response else {
+        return Ok(Vec::new());
+    };
+    result.decode().map_err(|e| {
+        wasm_error!(WasmErrorInner::Guest(format!(
+            "Failed to decode accountabilities: {e}"
+        )))
+    })
+}
+
+/// The StewardPermission based on which the calling agent can remove asset relations
+/// of other agents as an unscoped steward of the group right now, if any
+pub fn my_steward_permission_hash() -> ExternResult<Option<ActionHash>> {
+    let accountabilities = call_group_accountabilities(
+        "get_agent_accountabilities",
+        (agent_info()?.agent_initial_pubkey, sys_time()?),
+        GetStrategy::Network,
+    )?;
+    Ok(accountabilities
+        .into_iter()
+        .find_map(|accountability| match accountability {
+            GroupAccountability::Steward(GroupStewardPermissionClaim {
+                permission_hash,
+                permission: GroupStewardPermission { scope: None },
+            }) => Some(permission_hash),
+            _ => None,
+        }))
+}
+
+/// Whether the agent is the progenitor of the group or, based on the given
+/// StewardPermission, an unscoped steward of the group right now
+pub fn is_group_steward(
+    agent: AgentPubKey,
+    permission_hash: Option<ActionHash>,
+    strategy: GetStrategy,
+) -> ExternResult<bool> {
+    let accountabilities = call_group_accountabilities(
+        "get_agent_accountabilities_by_permission",
+        (agent, permission_hash),
+        strategy,
+    )?;
+    Ok(accountabilities.into_iter().any(|accountability| {
+        matches!(
+            accountability,
+            GroupAccountability::Progenitor
+                | GroupAccountability::Steward(GroupStewardPermissionClaim {
+                    permission: GroupStewardPermission { scope: None },
+                    ..
+                })
+        )
+    }))
+}
+
+/// Resolves the steward status of the authors of AssetRelationTombstones with as few
+/// calls into the group cell as possible, once per author and claimed permission
+pub struct StewardStatusCache {
+    strategy: GetStrategy,
+    known: HashMap<(AgentPubKey, Option<ActionHash>), bool>,
+}
+
+impl StewardStatusCache {
+    pub fn new(strategy: GetStrategy) -> Self {
+        Self {
+            strategy,
+            known: HashMap::new(),
+        }
+    }
+
+    pub fn is_group_steward(
+        &mut self,
+        agent: &AgentPubKey,
+        permission_hash: &Option<ActionHash>,
+    ) -> ExternResult<bool> {
+        let key = (agent.clone(), permission_hash.clone());
+        if let Some(is_steward) = self.known.get(&key) {
+            return Ok(*is_steward);
+        }
+        let is_steward = is_group_steward(agent.clone(), permission_hash.clone(), self.strategy)?;
+        self.known.insert(key, is_steward);
+        Ok(is_steward)
+    }
+}
+
+/// Whether a steward of the group has removed the asset relation. Since the links
+/// of other agents cannot be deleted by stewards, the AssetRelationTombstones of
+/// stewards hide asset relations on the read paths instead.
+///
+/// The timestamps of tombstones are chosen by their authors, so they are not taken
+/// into account. Instead a tombstone hides the asset relation only as long as the
+/// StewardPermission it references is in force at the time it gets read. Once that
+/// permission expires or gets revoked, the asset relations removed based on it show
+/// up again unless another steward removes them.
+pub fn is_asset_relation_removed_by_steward(
+    relation_hash: &EntryHash,
+    stewards: &mut StewardStatusCache,
+) -> ExternResult<bool> {
+    let links = get_links(
+        LinkQuery::try_new(relation_hash.clone(), LinkTypes::AssetRelationToTombstones)?,
+        stewards.strategy,
+    )?;
+    let get_options = match stewards.strategy {
+        GetStrategy::Local => GetOptions::local(),
+        GetStrategy::Network => GetOptions::network(),
+    };
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), get_options.clone()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    for record in records.into_iter().flatten() {
+        let author = record.action().author().clone();
+        let Ok(tombstone) = AssetRelationTombstone::try_from(record) else {
+            continue;
+        };
+        if stewards.is_group_steward(&author, &tombstone.steward_permission_hash)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Filters out the items of asset relations that have been removed by a steward. The
+/// steward status of each tombstone author is resolved once for all items.
+pub fn filter_removed_by_stewards<T>(
+    items: Vec<T>,
+    relation_hash: impl Fn(&T) -> &EntryHash,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<T>> {
+    let mut stewards = StewardStatusCache::new(strategy);
+    let mut filtered = Vec::new();
+    for item in items {
+        if !is_asset_relation_removed_by_steward(relation_hash(&item), &mut stewards)? {
+            filtered.push(item);
+        }
+    }
+    Ok(filtered)
+}
+
+/// Deletes a link of an asset relation. Links of other agents can only be deleted
+/// based on the AssetRelationTombstone with the given hash, which needs to reference
+/// a delegation from the creator of the link.
+pub fn remove_asset_relation_link(
+    create_link_hash: ActionHash,
+    creator: &AgentPubKey,
+    tombstone_hash: &ActionHash,
+) -> ExternResult<()> {
+    if creator != &agent_info()?.agent_initial_pubkey {
+        // Needs to be the action right before the delete
+        create_entry(&EntryTypes::AssetRelationLinkRemoval(
+            AssetRelationLinkRemoval {
+                tombstone_hash: tombstone_hash.clone(),
+                link_hash: create_link_hash.clone(),
+            },
+        ))?;
+    }
+    delete_link(create_link_hash, GetOptions::local())?;
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DelegateAssetRelationRemovalInput {
+    pub delegate: AgentPubKey,
+    /// Defaults to the maximum duration of a delegation from now
+    pub expiry: Option<Timestamp>,
+}
+
+/// Delegates the removal of the asset relations (and their tags) that the calling
+/// agent created to another agent until the given expiry
+#[hdk_extern]
+pub fn delegate_asset_relation_removal(
+    input: DelegateAssetRelationRemovalInput,
+) -> ExternResult<ActionHash> {
+    let expiry = match input.expiry {
+        Some(expiry) => expiry,
+        None => Timestamp::from_micros(
+            sys_time()?.as_micros() + MAX_ASSET_RELATION_DELEGATION_DURATION_MICROS,
+        ),
+    };
+    let tag = ExternIO::encode(AssetRelationDelegationLinkTag { expiry })
+        .map_err(|e| {
+            wasm_error!(WasmErrorInner::Guest(format!(
+                "Failed to encode link tag content: {e}"
+            )))
+        })?
+        .into_vec();
+    create_link(
+        agent_info()?.agent_initial_pubkey,
+        input.delegate,
+        LinkTypes::AgentToAssetRelationDelegates,
+        LinkTag(tag),
+    )
+}
+
+/// Revokes the delegations of the calling agent to the given agent. Honest delegates
+/// stop using them right away. Validation cannot tell whether a delegation has been
+/// revoked though, so a delegate could keep using it in tombstones and removals
+/// until it expires.
+#[hdk_extern]
+pub fn revoke_asset_relation_removal_delegation(delegate: AgentPubKey) -> ExternResult<()> {
+    let links = get_links(
+        LinkQuery::try_new(
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::AgentToAssetRelationDelegates,
+        )?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        if link.target == AnyLinkableHash::from(delegate.clone()) {
+            delete_link(link.create_link_hash, GetOptions::local())?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the delegation made with the given link has not expired yet
+fn is_delegation_unexpired(link: &Link, now: Timestamp) -> bool {
+    ExternIO::from(link.tag.0.clone())
+        .decode::<AssetRelationDelegationLinkTag>()
+        .is_ok_and(|tag| tag.expiry > now)
+}
+
+/// Gets the agents that the given agent has delegated the removal of their asset
+/// relations to and whose delegations have not expired yet
+#[hdk_extern]
+pub fn get_asset_relation_removal_delegates(
+    agent: ZomeFnInput<AgentPubKey>,
+) -> ExternResult<Vec<AgentPubKey>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            agent.input.clone(),
+            LinkTypes::AgentToAssetRelationDelegates,
+        )?,
+        agent.get_strategy(),
+    )?;
+    let now = sys_time()?;
+    let mut delegates: Vec<AgentPubKey> = links
+        .into_iter()
+        .filter(|link| is_delegation_unexpired(link, now))
+        .filter_map(|link| link.target.into_agent_pub_key())
+        .collect();
+    delegates.sort();
+    delegates.dedup();
+    Ok(delegates)
+}
+
+/// Gets the unexpired delegations from the given agents to the calling agent, at most
+/// one per delegating agent
+pub fn get_delegations_to_me(
+    delegators: &[AgentPubKey],
+) -> ExternResult<Vec<(AgentPubKey, ActionHash)>> {
+    let my_pub_key = AnyLinkableHash::from(agent_info()?.agent_initial_pubkey);
+    let now = sys_time()?;
+    let mut delegations = Vec::new();
+    for delegator in delegators {
+        let links = get_links(
+            LinkQuery::try_new(delegator.clone(), LinkTypes::AgentToAssetRelationDelegates)?,
+            GetStrategy::Network,
+        )?;
+        if let Some(link) = links
+            .into_iter()
+            .find(|link| link.target == my_pub_key && is_delegation_unexpired(link, now))
+        {
+            delegations.push((delegator.clone(), link.create_link_hash));
+        }
+    }
+    Ok(delegations)
+}
+
+/// Gets the AssetRelationTombstones recorded for an asset relation, oldest first.
+/// Each record carries the agent that removed the asset relation and when.
+#[hdk_extern]
+pub fn get_asset_relation_tombstones(
+    relation_hash: ZomeFnInput<EntryHash>,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            relation_hash.input.clone(),
+            LinkTypes::AssetRelationToTombstones,
+        )?,
+        relation_hash.get_strategy(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), relation_hash.get_options()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut tombstones: Vec<Record> = records.into_iter().flatten().collect();
+    tombstones.sort_by(|a, b| {
+        a.action()
+            .timestamp()
+            .cmp(&b.action().timestamp())
+            .then_with(|| a.action_address().cmp(b.action_address()))
+    });
+    Ok(tombstones)
+}
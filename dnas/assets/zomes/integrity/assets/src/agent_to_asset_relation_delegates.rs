@@ -0,0 +1,77 @@
+use hdi::prelude::*;
+
+/// Maximum duration of a delegation of the removal of asset relations (90 days).
+/// Delegations need to expire since validation cannot tell whether the delegating
+/// agent has revoked a delegation at the time it gets used.
+pub const MAX_ASSET_RELATION_DELEGATION_DURATION_MICROS: i64 = 90 * 24 * 60 * 60 * 1_000_000;
+
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct AssetRelationDelegationLinkTag {
+    /// Time after which the delegation cannot be used anymore
+    pub expiry: Timestamp,
+}
+
+/// The expiry of the delegation made with the given AgentToAssetRelationDelegates
+/// link, if its tag is well formed
+pub fn asset_relation_delegation_expiry(create_link: &CreateLink) -> Option<Timestamp> {
+    ExternIO::from(create_link.tag.0.clone())
+        .decode::<AssetRelationDelegationLinkTag>()
+        .ok()
+        .map(|tag| tag.expiry)
+}
+
+/// Rules
+/// 1. Agents can only delegate the removal of their own asset relations, i.e. the
+///    base of the link must be the public key of its creator
+/// 2. Link must point to the public key of the delegate
+/// 3. The tag must contain an expiry after the creation of the link and at most
+///    MAX_ASSET_RELATION_DELEGATION_DURATION_MICROS after it
+pub fn validate_create_link_agent_to_asset_relation_delegates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address != AnyLinkableHash::from(action.author.clone()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAssetRelationDelegates links must point away from the public key of their creator".into(),
+        ));
+    }
+    if target_address.into_agent_pub_key().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAssetRelationDelegates links must point to the public key of an agent".into(),
+        ));
+    }
+    let expiry = match ExternIO::from(tag.0).decode::<AssetRelationDelegationLinkTag>() {
+        Ok(tag) => tag.expiry,
+        Err(e) => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Link tag content format is invalid. Serialization error: {e}"
+            )))
+        }
+    };
+    let duration = expiry.as_micros() - action.timestamp.as_micros();
+    if duration <= 0 || duration > MAX_ASSET_RELATION_DELEGATION_DURATION_MICROS {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToAssetRelationDelegates links must expire within the maximum delegation duration".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the creator of the link can revoke the delegation
+pub fn validate_delete_link_agent_to_asset_relation_delegates(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AgentToAssetRelationDelegates link can delete it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
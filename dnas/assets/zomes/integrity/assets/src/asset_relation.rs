@@ -1,6 +1,27 @@
 use hdi::prelude::*;
 
+use crate::validate_asset_relation_link_removal;
+
 pub const ALL_ASSET_RELATIONS_ANCHOR: &str = "##ALL_ASSET_RELATIONS##";
+/// Number of anchors that AllAssetRelations links are spread across
+pub const ALL_ASSET_RELATIONS_BUCKETS: u8 = 16;
+
+/// The bucket anchor from which the given asset relation gets linked. Asset relations
+/// used to be linked from the ALL_ASSET_RELATIONS_ANCHOR directly, which became a DHT
+/// hotspot.
+pub fn all_asset_relations_bucket_anchor(relation_hash: &EntryHash) -> Path {
+    all_asset_relations_bucket_anchor_by_index(
+        relation_hash.get_raw_32()[0] % ALL_ASSET_RELATIONS_BUCKETS,
+    )
+}
+
+pub fn all_asset_relations_bucket_anchor_by_index(bucket: u8) -> Path {
+    Path::from(vec![
+        Component::from(ALL_ASSET_RELATIONS_ANCHOR),
+        Component::from(vec![bucket]),
+    ])
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct HRL(DnaHash, AnyDhtHash);
 
@@ -38,12 +59,18 @@ pub fn validate_update_asset_relation(
     ))
 }
 
-/// Anyone can delete an asset relation. Restrictions would need to be enorced at the UI level
+/// Only the creator of an asset relation can delete it. Other agents remove an asset
+/// relation by deleting its links, see validate_asset_relation_link_removal
 pub fn validate_delete_asset_relation(
-    _action: Delete,
-    _original_action: EntryCreationAction,
+    action: Delete,
+    original_action: EntryCreationAction,
     _original_asset_relation: AssetRelation,
 ) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an asset relation can delete it".into(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -53,11 +80,14 @@ pub fn validate_create_link_all_asset_relations(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if let None = target_address.into_entry_hash() {
-        return Ok(ValidateCallbackResult::Invalid(
-            "Base address is not an entry hash.".into(),
-        ));
-    }
+    let relation_hash = match target_address.into_entry_hash() {
+        Some(relation_hash) => relation_hash,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Base address is not an entry hash.".into(),
+            ))
+        }
+    };
     match base_address.into_entry_hash() {
         None => {
             return Ok(ValidateCallbackResult::Invalid(
@@ -66,7 +96,9 @@ pub fn validate_create_link_all_asset_relations(
         }
         Some(eh) => {
             let path = Path::from(ALL_ASSET_RELATIONS_ANCHOR);
-            if path.path_entry_hash()? != eh {
+            if path.path_entry_hash()? != eh
+                && all_asset_relations_bucket_anchor(&relation_hash).path_entry_hash()? != eh
+            {
                 return Ok(ValidateCallbackResult::Invalid(
                     "AllAssetRelations link is not pointing away from the correct anchor".into(),
                 ));
@@ -76,12 +108,14 @@ pub fn validate_create_link_all_asset_relations(
     }
 }
 
+/// Rules
+/// 1. Only the creator of the link or agents delegated by them can delete it
 pub fn validate_delete_link_all_asset_relations(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_asset_relation_link_removal(&action, &original_action)
 }
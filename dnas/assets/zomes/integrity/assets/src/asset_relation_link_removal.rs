@@ -0,0 +1,129 @@
+use hdi::prelude::*;
+
+use crate::{asset_relation_delegation_expiry, AssetRelationTombstone, LinkTypes};
+
+/// Authorizes the removal of a link of an asset relation that another agent has
+/// created. It needs to be committed right before the DeleteLink, so that validating
+/// the DeleteLink only needs to get the previous record of the remover.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AssetRelationLinkRemoval {
+    /// ActionHash of the AssetRelationTombstone based on which the link gets removed
+    pub tombstone_hash: ActionHash,
+    /// ActionHash of the CreateLink of the link that gets removed
+    pub link_hash: ActionHash,
+}
+
+/// The asset relation that the link is part of, if it is one of the links that
+/// make up an asset relation
+pub fn asset_relation_of_link(create_link: &CreateLink) -> ExternResult<Option<EntryHash>> {
+    Ok(
+        match LinkTypes::from_type(create_link.zome_index, create_link.link_type)? {
+            Some(LinkTypes::AssetRelationToRelationshipTags) => {
+                create_link.base_address.clone().into_entry_hash()
+            }
+            Some(
+                LinkTypes::RelationshipTagToAssetRelation
+                | LinkTypes::SrcWalToAssetRelations
+                | LinkTypes::DstWalToAssetRelations
+                | LinkTypes::AllAssetRelations,
+            ) => create_link.target_address.clone().into_entry_hash(),
+            _ => None,
+        },
+    )
+}
+
+/// Rules
+/// 1. The tombstone hash must point to an AssetRelationTombstone of the author
+/// 2. The link hash must point to a link of the asset relation of the tombstone
+/// 3. The tombstone must reference a delegation from the creator of the link to the
+///    author that has not expired at the time of the removal
+pub fn validate_create_asset_relation_link_removal(
+    action: EntryCreationAction,
+    removal: AssetRelationLinkRemoval,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(removal.tombstone_hash)?;
+    if record.action().author() != action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AssetRelationLinkRemovals must reference an AssetRelationTombstone of their author"
+                .into(),
+        ));
+    }
+    let Ok(tombstone) = AssetRelationTombstone::try_from(record) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "tombstone_hash does not point to an AssetRelationTombstone entry".into(),
+        ));
+    };
+    let link_action = must_get_action(removal.link_hash)?;
+    let Action::CreateLink(create_link) = link_action.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "link_hash does not point to a CreateLink action".into(),
+        ));
+    };
+    if asset_relation_of_link(create_link)? != Some(tombstone.relation_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is not part of the asset relation of the AssetRelationTombstone".into(),
+        ));
+    }
+    for delegation_hash in tombstone.delegation_hashes {
+        let delegation = must_get_action(delegation_hash)?;
+        let Action::CreateLink(delegation) = delegation.action() else {
+            continue;
+        };
+        if delegation.author == create_link.author
+            && asset_relation_delegation_expiry(delegation)
+                .is_some_and(|expiry| expiry >= *action.timestamp())
+        {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "AssetRelationTombstone does not reference an unexpired delegation from the creator of the link"
+            .into(),
+    ))
+}
+pub fn validate_update_asset_relation_link_removal(
+    _action: Update,
+    _removal: AssetRelationLinkRemoval,
+    _original_action: EntryCreationAction,
+    _original_removal: AssetRelationLinkRemoval,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AssetRelationLinkRemovals cannot be updated",
+    )))
+}
+pub fn validate_delete_asset_relation_link_removal(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_removal: AssetRelationLinkRemoval,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AssetRelationLinkRemovals cannot be deleted",
+    )))
+}
+
+/// Rules for removing the links that make up an asset relation
+/// 1. The creator of a link can remove it
+/// 2. Other agents need to commit an AssetRelationLinkRemoval for the link right
+///    before removing it
+///
+/// Stewards of the group cannot be recognized here since they are defined in the
+/// group DNA. Their tombstones hide asset relations on the read paths instead.
+pub fn validate_asset_relation_link_removal(
+    action: &DeleteLink,
+    original_action: &CreateLink,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let record = must_get_valid_record(action.prev_action.clone())?;
+    match AssetRelationLinkRemoval::try_from(record) {
+        Ok(removal) if removal.link_hash == action.link_add_address => {
+            Ok(ValidateCallbackResult::Valid)
+        }
+        _ => Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a link of an asset relation or agents delegated by them can remove it"
+                .into(),
+        )),
+    }
+}
@@ -1,5 +1,7 @@
 use hdi::prelude::*;
 
+use crate::validate_asset_relation_link_removal;
+
 #[derive(Serialize, Deserialize, SerializedBytes, Debug)]
 pub struct LinkTagContent {
     pub tag: String,
@@ -46,14 +48,16 @@ pub fn validate_create_link_asset_relation_to_relationship_tags(
     Ok(ValidateCallbackResult::Valid)
 }
 
+/// Rules
+/// 1. Only the creator of the link or agents delegated by them can delete it
 pub fn validate_delete_link_asset_relation_to_relationship_tags(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_asset_relation_link_removal(&action, &original_action)
 }
 
 pub fn relationship_tag_entry_hash(tag: &String) -> ExternResult<EntryHash> {
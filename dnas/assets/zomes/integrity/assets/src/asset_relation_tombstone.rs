@@ -0,0 +1,133 @@
+use hdi::prelude::*;
+
+use crate::{asset_relation_delegation_expiry, AssetRelation, LinkTypes};
+
+/// Records the removal of an asset relation so that it can be audited. The author
+/// of the tombstone is the agent that removed the relation.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AssetRelationTombstone {
+    pub relation_hash: EntryHash,
+    pub reason: Option<String>,
+    /// ActionHashes of the AgentToAssetRelationDelegates links with which the
+    /// creators of the links of the relation delegated its removal to the remover
+    pub delegation_hashes: Vec<ActionHash>,
+    /// ActionHash of the StewardPermission in the group based on which the remover
+    /// removed the relation as a steward. Whether it is in force is checked when the
+    /// tombstone gets read, see the removals module of the coordinator zome.
+    #[serde(default)]
+    pub steward_permission_hash: Option<ActionHash>,
+}
+
+/// Rules
+/// 1. The relation hash must point to an AssetRelation entry
+/// 2. The delegation hashes must point to AgentToAssetRelationDelegates links that
+///    delegate to the author of the tombstone and have not expired at the time of
+///    the tombstone
+pub fn validate_create_asset_relation_tombstone(
+    action: EntryCreationAction,
+    tombstone: AssetRelationTombstone,
+) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(tombstone.relation_hash.clone())?;
+    if AssetRelation::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "relation_hash does not point to an AssetRelation entry.".into(),
+        ));
+    }
+    for delegation_hash in tombstone.delegation_hashes {
+        let record = must_get_valid_record(delegation_hash)?;
+        let is_delegation_to_author = match record.action() {
+            Action::CreateLink(create_link) => {
+                matches!(
+                    LinkTypes::from_type(create_link.zome_index, create_link.link_type)?,
+                    Some(LinkTypes::AgentToAssetRelationDelegates)
+                ) && create_link.target_address == AnyLinkableHash::from(action.author().clone())
+                    && asset_relation_delegation_expiry(create_link)
+                        .is_some_and(|expiry| expiry >= *action.timestamp())
+            }
+            _ => false,
+        };
+        if !is_delegation_to_author {
+            return Ok(ValidateCallbackResult::Invalid(
+                "delegation_hashes must point to unexpired AgentToAssetRelationDelegates links delegating to the author of the tombstone.".into(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_asset_relation_tombstone(
+    _action: Update,
+    _tombstone: AssetRelationTombstone,
+    _original_action: EntryCreationAction,
+    _original_tombstone: AssetRelationTombstone,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AssetRelationTombstones cannot be updated",
+    )))
+}
+pub fn validate_delete_asset_relation_tombstone(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_tombstone: AssetRelationTombstone,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AssetRelationTombstones cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point from an AssetRelation entry hash to an AssetRelationTombstone
+/// 2. The AssetRelationTombstone must be for the AssetRelation that the link is
+///    pointing away from
+/// 3. The creator of the link must be the one that created the AssetRelationTombstone
+pub fn validate_create_link_asset_relation_to_tombstones(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let relation_hash =
+        base_address
+            .into_entry_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link base is not an entry hash".to_string()
+            )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let tombstone: AssetRelationTombstone = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an AssetRelationTombstone entry".to_string()
+        )))?;
+
+    if tombstone.relation_hash != relation_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to an AssetRelationTombstone of a different AssetRelation".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AssetRelationTombstone entry can create a link to it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_asset_relation_to_tombstones(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AssetRelationToTombstones links cannot be deleted",
+    )))
+}
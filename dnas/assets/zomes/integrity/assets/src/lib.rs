@@ -11,6 +11,12 @@ pub use asset_relation_to_relationship_tags::*;
 pub mod relationship_tag_to_asset_relation;
 use hdi::prelude::*;
 pub use relationship_tag_to_asset_relation::*;
+pub mod asset_relation_tombstone;
+pub use asset_relation_tombstone::*;
+pub mod agent_to_asset_relation_delegates;
+pub use agent_to_asset_relation_delegates::*;
+pub mod asset_relation_link_removal;
+pub use asset_relation_link_removal::*;
 
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -18,6 +24,8 @@ pub use relationship_tag_to_asset_relation::*;
 #[unit_enum(UnitEntryTypes)]
 pub enum EntryTypes {
     AssetRelation(AssetRelation),
+    AssetRelationTombstone(AssetRelationTombstone),
+    AssetRelationLinkRemoval(AssetRelationLinkRemoval),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -30,6 +38,8 @@ pub enum LinkTypes {
     WalToAssociationTags,
     AssociationTagToWals,
     AllAssetRelations,
+    AssetRelationToTombstones,
+    AgentToAssetRelationDelegates,
 }
 
 // Validation you perform during the genesis process. Nobody else on the network performs it, only you.
@@ -75,6 +85,18 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryCreationAction::Create(action),
                     asset_relation,
                 ),
+                EntryTypes::AssetRelationTombstone(asset_relation_tombstone) => {
+                    validate_create_asset_relation_tombstone(
+                        EntryCreationAction::Create(action),
+                        asset_relation_tombstone,
+                    )
+                }
+                EntryTypes::AssetRelationLinkRemoval(asset_relation_link_removal) => {
+                    validate_create_asset_relation_link_removal(
+                        EntryCreationAction::Create(action),
+                        asset_relation_link_removal,
+                    )
+                }
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -83,6 +105,18 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryCreationAction::Update(action),
                     asset_relation,
                 ),
+                EntryTypes::AssetRelationTombstone(asset_relation_tombstone) => {
+                    validate_create_asset_relation_tombstone(
+                        EntryCreationAction::Update(action),
+                        asset_relation_tombstone,
+                    )
+                }
+                EntryTypes::AssetRelationLinkRemoval(asset_relation_link_removal) => {
+                    validate_create_asset_relation_link_removal(
+                        EntryCreationAction::Update(action),
+                        asset_relation_link_removal,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -119,6 +153,44 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_asset_relation,
                         )
                     }
+                    EntryTypes::AssetRelationTombstone(asset_relation_tombstone) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_asset_relation_tombstone =
+                            match AssetRelationTombstone::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AssetRelationTombstone from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_asset_relation_tombstone(
+                            action,
+                            asset_relation_tombstone,
+                            original_create_action,
+                            original_asset_relation_tombstone,
+                        )
+                    }
+                    EntryTypes::AssetRelationLinkRemoval(asset_relation_link_removal) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_asset_relation_link_removal =
+                            match AssetRelationLinkRemoval::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AssetRelationLinkRemoval from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_asset_relation_link_removal(
+                            action,
+                            asset_relation_link_removal,
+                            original_create_action,
+                            original_asset_relation_link_removal,
+                        )
+                    }
                 }
             }
             _ => Ok(ValidateCallbackResult::Valid),
@@ -170,6 +242,20 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         original_asset_relation,
                     )
                 }
+                EntryTypes::AssetRelationTombstone(original_asset_relation_tombstone) => {
+                    validate_delete_asset_relation_tombstone(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_asset_relation_tombstone,
+                    )
+                }
+                EntryTypes::AssetRelationLinkRemoval(original_asset_relation_link_removal) => {
+                    validate_delete_asset_relation_link_removal(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_asset_relation_link_removal,
+                    )
+                }
             }
         }
         FlatOp::RegisterCreateLink {
@@ -222,6 +308,22 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::AllAssetRelations => {
                 validate_create_link_all_asset_relations(action, base_address, target_address, tag)
             }
+            LinkTypes::AssetRelationToTombstones => {
+                validate_create_link_asset_relation_to_tombstones(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AgentToAssetRelationDelegates => {
+                validate_create_link_agent_to_asset_relation_delegates(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
         },
         FlatOp::RegisterDeleteLink {
             link_type,
@@ -284,6 +386,24 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
+            LinkTypes::AssetRelationToTombstones => {
+                validate_delete_link_asset_relation_to_tombstones(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AgentToAssetRelationDelegates => {
+                validate_delete_link_agent_to_asset_relation_delegates(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
         },
         FlatOp::StoreRecord(store_record) => {
             match store_record {
@@ -295,6 +415,18 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         EntryCreationAction::Create(action),
                         asset_relation,
                     ),
+                    EntryTypes::AssetRelationTombstone(asset_relation_tombstone) => {
+                        validate_create_asset_relation_tombstone(
+                            EntryCreationAction::Create(action),
+                            asset_relation_tombstone,
+                        )
+                    }
+                    EntryTypes::AssetRelationLinkRemoval(asset_relation_link_removal) => {
+                        validate_create_asset_relation_link_removal(
+                            EntryCreationAction::Create(action),
+                            asset_relation_link_removal,
+                        )
+                    }
                 },
                 // Complementary validation to the `RegisterUpdate` Op, in which the record itself is validated
                 // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `StoreEntry` and in `RegisterUpdate`
@@ -350,6 +482,76 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 Ok(result)
                             }
                         }
+                        EntryTypes::AssetRelationTombstone(asset_relation_tombstone) => {
+                            let result = validate_create_asset_relation_tombstone(
+                                EntryCreationAction::Update(action.clone()),
+                                asset_relation_tombstone.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_asset_relation_tombstone: Option<
+                                    AssetRelationTombstone,
+                                > = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_asset_relation_tombstone =
+                                    match original_asset_relation_tombstone {
+                                        Some(asset_relation_tombstone) => asset_relation_tombstone,
+                                        None => {
+                                            return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                        }
+                                    };
+                                validate_update_asset_relation_tombstone(
+                                    action,
+                                    asset_relation_tombstone,
+                                    original_action,
+                                    original_asset_relation_tombstone,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::AssetRelationLinkRemoval(asset_relation_link_removal) => {
+                            let result = validate_create_asset_relation_link_removal(
+                                EntryCreationAction::Update(action.clone()),
+                                asset_relation_link_removal.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_asset_relation_link_removal: Option<
+                                    AssetRelationLinkRemoval,
+                                > = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_asset_relation_link_removal =
+                                    match original_asset_relation_link_removal {
+                                        Some(asset_relation_link_removal) => {
+                                            asset_relation_link_removal
+                                        }
+                                        None => {
+                                            return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                        }
+                                    };
+                                validate_update_asset_relation_link_removal(
+                                    action,
+                                    asset_relation_link_removal,
+                                    original_action,
+                                    original_asset_relation_link_removal,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
                     }
                 }
                 // Complementary validation to the `RegisterDelete` Op, in which the record itself is validated
@@ -409,6 +611,20 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_asset_relation,
                             )
                         }
+                        EntryTypes::AssetRelationTombstone(original_asset_relation_tombstone) => {
+                            validate_delete_asset_relation_tombstone(
+                                action,
+                                original_action,
+                                original_asset_relation_tombstone,
+                            )
+                        }
+                        EntryTypes::AssetRelationLinkRemoval(
+                            original_asset_relation_link_removal,
+                        ) => validate_delete_asset_relation_link_removal(
+                            action,
+                            original_action,
+                            original_asset_relation_link_removal,
+                        ),
                     }
                 }
                 // Complementary validation to the `RegisterCreateLink` Op, in which the record itself is validated
@@ -475,6 +691,22 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         target_address,
                         tag,
                     ),
+                    LinkTypes::AssetRelationToTombstones => {
+                        validate_create_link_asset_relation_to_tombstones(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
+                    LinkTypes::AgentToAssetRelationDelegates => {
+                        validate_create_link_agent_to_asset_relation_delegates(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
                 },
                 // Complementary validation to the `RegisterDeleteLink` Op, in which the record itself is validated
                 // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `RegisterDeleteLink`
@@ -565,6 +797,24 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             create_link.target_address,
                             create_link.tag,
                         ),
+                        LinkTypes::AssetRelationToTombstones => {
+                            validate_delete_link_asset_relation_to_tombstones(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToAssetRelationDelegates => {
+                            validate_delete_link_agent_to_asset_relation_delegates(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
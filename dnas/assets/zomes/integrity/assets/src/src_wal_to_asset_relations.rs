@@ -1,5 +1,7 @@
 use hdi::prelude::*;
 
+use crate::validate_asset_relation_link_removal;
+
 pub fn validate_create_link_src_wal_to_asset_relations(
     _action: CreateLink,
     _base_address: AnyLinkableHash,
@@ -9,12 +11,14 @@ pub fn validate_create_link_src_wal_to_asset_relations(
     Ok(ValidateCallbackResult::Valid)
 }
 
+/// Rules
+/// 1. Only the creator of the link or agents delegated by them can delete it
 pub fn validate_delete_link_src_wal_to_asset_relations(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_asset_relation_link_removal(&action, &original_action)
 }
@@ -1,19 +1,57 @@
 use custom_views_integrity::*;
 use hdk::prelude::*;
-use moss_helpers::ZomeFnInput;
-#[hdk_extern]
-pub fn get_all_custom_views(input: ZomeFnInput<()>) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_custom_views");
+use moss_helpers::{get_bucketed_targets_page, Page, PaginationInput, ZomeFnInput};
+
+/// The anchors from which custom views get linked, with the legacy all_custom_views
+/// anchor last
+fn all_custom_views_anchors() -> ExternResult<Vec<EntryHash>> {
+    let mut anchors = (0..ALL_CUSTOM_VIEWS_BUCKETS)
+        .map(|bucket| all_custom_views_bucket_anchor_by_index(bucket).path_entry_hash())
+        .collect::<ExternResult<Vec<EntryHash>>>()?;
+    anchors.push(Path::from(ALL_CUSTOM_VIEWS_ANCHOR).path_entry_hash()?);
+    Ok(anchors)
+}
+
+fn get_all_custom_views_targets(
+    anchor: &EntryHash,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<AnyLinkableHash>> {
     let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllCustomViews)?
-           , input.into()
+        LinkQuery::try_new(anchor.clone(), LinkTypes::AllCustomViews)?,
+        strategy,
     )?;
-    let get_input: Vec<GetInput> = links
+    Ok(links.into_iter().map(|link| link.target).collect())
+}
+
+fn get_custom_view_records(targets: Vec<AnyLinkableHash>) -> ExternResult<Vec<Record>> {
+    let get_input: Vec<GetInput> = targets
         .into_iter()
-        .filter_map(|link| link.target.into_action_hash())
+        .filter_map(|target| target.into_action_hash())
         .map(|action_hash| GetInput::new(action_hash.into(), GetOptions::local()))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
-    Ok(records)
+    Ok(records.into_iter().flatten().collect())
+}
+
+#[hdk_extern]
+pub fn get_all_custom_views(input: ZomeFnInput<()>) -> ExternResult<Vec<Record>> {
+    let mut targets = Vec::new();
+    for anchor in all_custom_views_anchors()? {
+        targets.extend(get_all_custom_views_targets(&anchor, input.get_strategy())?);
+    }
+    get_custom_view_records(targets)
+}
+
+/// Paginated version of get_all_custom_views
+#[hdk_extern]
+pub fn get_all_custom_views_page(
+    input: ZomeFnInput<PaginationInput>,
+) -> ExternResult<Page<Record>> {
+    let page = get_bucketed_targets_page(&all_custom_views_anchors()?, &input.input, |anchor| {
+        get_all_custom_views_targets(anchor, input.get_strategy())
+    })?;
+    Ok(Page {
+        items: get_custom_view_records(page.items)?,
+        next_cursor: page.next_cursor,
+    })
 }
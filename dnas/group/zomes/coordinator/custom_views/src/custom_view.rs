@@ -1,53 +1,111 @@
 use custom_views_integrity::*;
 use hdk::prelude::*;
 use moss_helpers::ZomeFnInput;
+use steward_permissions::StewardDeleteAuthorization;
+/// Refuses to act based on a StewardPermission that has been revoked, as determined
+/// by the group zome
+fn check_steward_permission_not_revoked(permission_hash: Option<ActionHash>) -> ExternResult<()> {
+    if permission_hash.is_none() {
+        return Ok(());
+    }
+    match call(
+        CallTargetCell::Local,
+        ZomeName::from("group"),
+        FunctionName::from("check_steward_permission_not_revoked"),
+        None,
+        permission_hash,
+    )? {
+        ZomeCallResponse::Ok(_) => Ok(()),
+        response => Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "Failed to check the StewardPermission for revocations: {response:?}"
+        )))),
+    }
+}
 #[hdk_extern]
 pub fn create_custom_view(custom_view: CustomView) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(custom_view.permission_hash.clone())?;
     let custom_view_hash = create_entry(&EntryTypes::CustomView(custom_view.clone()))?;
     let record = get(custom_view_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
         WasmErrorInner::Guest(String::from("Could not find the newly created CustomView"))
     ))?;
-    let path = Path::from("all_custom_views");
+    let path = all_custom_views_bucket_anchor(&custom_view_hash);
     create_link(
         path.path_entry_hash()?,
         custom_view_hash.clone(),
         LinkTypes::AllCustomViews,
         (),
     )?;
+    if let Some(target) = custom_view.target {
+        create_link(
+            target.anchor().path_entry_hash()?,
+            custom_view_hash.clone(),
+            LinkTypes::CustomViewTargetToCustomViews,
+            (),
+        )?;
+    }
     Ok(record)
 }
+/// Gets the revision of a custom view that members should render. That is the
+/// version pinned by a steward if any, otherwise the latest revision as determined
+/// by get_custom_view_history. Returns None if the custom view has been deleted.
 #[hdk_extern]
 pub fn get_custom_view(
     original_custom_view_hash: ZomeFnInput<ActionHash>,
 ) -> ExternResult<Option<Record>> {
-    get_latest_custom_view(original_custom_view_hash)
-}
-fn get_latest_custom_view(
-    custom_view_hash: ZomeFnInput<ActionHash>,
-) -> ExternResult<Option<Record>> {
-    let details = get_details(
-        custom_view_hash.input.clone(),
-        custom_view_hash.get_options(),
+    let Some(Details::Record(details)) = get_details(
+        original_custom_view_hash.input.clone(),
+        original_custom_view_hash.get_options(),
     )?
-    .ok_or(wasm_error!(WasmErrorInner::Guest(
-        "CustomView not found".into()
-    )))?;
-    let record_details = match details {
-        Details::Entry(_) => Err(wasm_error!(WasmErrorInner::Guest(
-            "Malformed details".into()
-        ))),
-        Details::Record(record_details) => Ok(record_details),
-    }?;
-    if record_details.deletes.len() > 0 {
+    else {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "CustomView not found".into()
+        )));
+    };
+    if !details.deletes.is_empty() {
         return Ok(None);
     }
-    match record_details.updates.last() {
-        Some(update) => get_latest_custom_view(ZomeFnInput::new(
-            update.action_address().clone(),
-            custom_view_hash.local,
-        )),
-        None => Ok(Some(record_details.record)),
+    if let Some(version_hash) = get_custom_view_active_version(original_custom_view_hash.clone())?
+        .and_then(|active_version| active_version.version_hash)
+    {
+        if let Some(record) = get(version_hash, original_custom_view_hash.get_options())? {
+            return Ok(Some(record));
+        }
+    }
+    Ok(get_custom_view_history(original_custom_view_hash)?.pop())
+}
+/// Gets all revisions of a custom view. Revisions are ordered by their position in
+/// the update chain, i.e. by the number of updates that lead to them from the
+/// originally created CustomView. Revisions at the same position, which result from
+/// concurrent updates, are ordered by their action hash so that all agents resolve
+/// forks to the same latest revision.
+#[hdk_extern]
+pub fn get_custom_view_history(
+    original_custom_view_hash: ZomeFnInput<ActionHash>,
+) -> ExternResult<Vec<Record>> {
+    let mut revisions: Vec<(usize, Record)> = Vec::new();
+    let mut to_visit: Vec<(usize, ActionHash)> = vec![(0, original_custom_view_hash.input.clone())];
+    while let Some((depth, action_hash)) = to_visit.pop() {
+        if revisions
+            .iter()
+            .any(|(_, record)| record.action_address() == &action_hash)
+        {
+            continue;
+        }
+        if let Some(Details::Record(details)) =
+            get_details(action_hash, original_custom_view_hash.get_options())?
+        {
+            for update in details.updates {
+                to_visit.push((depth + 1, update.hashed.hash));
+            }
+            revisions.push((depth, details.record));
+        }
     }
+    revisions.sort_by(|(depth_a, record_a), (depth_b, record_b)| {
+        depth_a
+            .cmp(depth_b)
+            .then_with(|| record_a.action_address().cmp(record_b.action_address()))
+    });
+    Ok(revisions.into_iter().map(|(_, record)| record).collect())
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UpdateCustomViewInput {
@@ -56,6 +114,7 @@ pub struct UpdateCustomViewInput {
 }
 #[hdk_extern]
 pub fn update_custom_view(input: UpdateCustomViewInput) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(input.updated_custom_view.permission_hash.clone())?;
     let updated_custom_view_hash =
         update_entry(input.previous_custom_view_hash, &input.updated_custom_view)?;
     let record =
@@ -64,7 +123,95 @@ pub fn update_custom_view(input: UpdateCustomViewInput) -> ExternResult<Record>
         ))?;
     Ok(record)
 }
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeleteCustomViewInput {
+    pub original_custom_view_hash: ActionHash,
+    /// ActionHash of the StewardPermission of the calling agent if they are not the
+    /// author of the custom view
+    pub permission_hash: Option<ActionHash>,
+}
 #[hdk_extern]
-pub fn delete_custom_view(original_custom_view_hash: ActionHash) -> ExternResult<ActionHash> {
-    delete_entry(original_custom_view_hash)
+pub fn delete_custom_view(input: DeleteCustomViewInput) -> ExternResult<ActionHash> {
+    if let Some(permission_hash) = input.permission_hash {
+        // Stewards need to authorize the delete through the group zome right before it
+        let authorization = StewardDeleteAuthorization {
+            permission_hash,
+            deleted_action_hash: input.original_custom_view_hash.clone(),
+        };
+        match call(
+            CallTargetCell::Local,
+            ZomeName::from("group"),
+            FunctionName::from("authorize_steward_delete"),
+            None,
+            authorization,
+        )? {
+            ZomeCallResponse::Ok(_) => (),
+            response => {
+                return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                    "Failed to authorize the deletion of the custom view: {response:?}"
+                ))))
+            }
+        }
+    }
+    delete_entry(input.original_custom_view_hash)
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetCustomViewActiveVersionInput {
+    pub permission_hash: Option<ActionHash>,
+    pub custom_view_hash: ActionHash,
+    /// The revision to pin. None unpins the custom view.
+    pub version_hash: Option<ActionHash>,
+}
+/// Pins the revision of a custom view that members render. Only stewards can do that.
+#[hdk_extern]
+pub fn set_custom_view_active_version(
+    input: SetCustomViewActiveVersionInput,
+) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(input.permission_hash.clone())?;
+    let active_version = CustomViewActiveVersion {
+        permission_hash: input.permission_hash,
+        custom_view_hash: input.custom_view_hash.clone(),
+        version_hash: input.version_hash,
+    };
+    let active_version_hash = create_entry(&EntryTypes::CustomViewActiveVersion(active_version))?;
+    let record = get(active_version_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created CustomViewActiveVersion"
+        ))
+    ))?;
+    create_link(
+        input.custom_view_hash,
+        active_version_hash,
+        LinkTypes::CustomViewToActiveVersions,
+        (),
+    )?;
+    Ok(record)
+}
+/// Gets the latest CustomViewActiveVersion of a custom view. Concurrently pinned
+/// versions are ordered by the timestamp of their link and then by action hash.
+#[hdk_extern]
+pub fn get_custom_view_active_version(
+    original_custom_view_hash: ZomeFnInput<ActionHash>,
+) -> ExternResult<Option<CustomViewActiveVersion>> {
+    let mut links = get_links(
+        LinkQuery::try_new(
+            original_custom_view_hash.input.clone(),
+            LinkTypes::CustomViewToActiveVersions,
+        )?,
+        original_custom_view_hash.get_strategy(),
+    )?;
+    links.sort_by(|a, b| {
+        b.timestamp
+            .cmp(&a.timestamp)
+            .then_with(|| b.target.cmp(&a.target))
+    });
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if let Some(record) = get(action_hash, original_custom_view_hash.get_options())? {
+            return record.entry().to_app_option().map_err(|e| wasm_error!(e));
+        }
+    }
+    Ok(None)
 }
@@ -0,0 +1,47 @@
+use custom_views_integrity::*;
+use hdk::prelude::*;
+use moss_helpers::ZomeFnInput;
+
+use crate::custom_view::get_custom_view;
+
+/// Gets the custom views bound to the given target, each in the revision that
+/// members should render as determined by get_custom_view. Deleted custom views
+/// are omitted.
+#[hdk_extern]
+pub fn get_custom_views_for_target(
+    input: ZomeFnInput<CustomViewTarget>,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            input.input.anchor().path_entry_hash()?,
+            LinkTypes::CustomViewTargetToCustomViews,
+        )?,
+        input.get_strategy(),
+    )?;
+    let mut records = Vec::new();
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        // Custom views that cannot be found (yet) are skipped
+        if let Ok(Some(record)) = get_custom_view(ZomeFnInput::new(action_hash, input.local)) {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+/// Gets the custom views bound to the Applet with the given entry hash
+#[hdk_extern]
+pub fn get_custom_views_for_applet(input: ZomeFnInput<EntryHash>) -> ExternResult<Vec<Record>> {
+    get_custom_views_for_target(ZomeFnInput::new(
+        CustomViewTarget::Applet(input.input),
+        input.local,
+    ))
+}
+
+/// Gets the custom views bound to the home view of the group
+#[hdk_extern]
+pub fn get_custom_views_for_group_home(input: ZomeFnInput<()>) -> ExternResult<Vec<Record>> {
+    get_custom_views_for_target(ZomeFnInput::new(CustomViewTarget::GroupHome, input.local))
+}
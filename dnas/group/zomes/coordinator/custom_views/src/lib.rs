@@ -1,5 +1,6 @@
 pub mod all_custom_views;
 pub mod custom_view;
+pub mod custom_view_target;
 use custom_views_integrity::*;
 use hdk::prelude::*;
 #[hdk_extern]
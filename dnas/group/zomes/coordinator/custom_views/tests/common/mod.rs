@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use hdk::prelude::*;
 use holochain::sweettest::*;
 
@@ -10,6 +12,8 @@ pub async fn sample_custom_view_1(conductor: &SweetConductor, zome: &SweetZome)
         html: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
         js: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
         css: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
+        permission_hash: None,
+        target: None,
     }
 }
 
@@ -20,6 +24,8 @@ pub async fn sample_custom_view_2(conductor: &SweetConductor, zome: &SweetZome)
         html: "Lorem ipsum 2".to_string(),
         js: "Lorem ipsum 2".to_string(),
         css: "Lorem ipsum 2".to_string(),
+        permission_hash: None,
+        target: None,
     }
 }
 
@@ -33,3 +39,34 @@ pub async fn create_custom_view(
         .await;
     record
 }
+
+/// Mirror of the Applet entry of the group zome
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Applet {
+    pub permission_hash: Option<ActionHash>,
+    pub custom_name: String,
+    pub description: String,
+    pub sha256_happ: String,
+    pub sha256_ui: Option<String>,
+    pub sha256_webhapp: Option<String>,
+    pub distribution_info: String,
+    pub meta_data: Option<String>,
+    pub network_seed: Option<String>,
+    pub properties: BTreeMap<String, SerializedBytes>,
+}
+
+pub async fn register_sample_applet(conductor: &SweetConductor, zome: &SweetZome) -> EntryHash {
+    let applet = Applet {
+        permission_hash: None,
+        custom_name: String::from("Applet 1"),
+        description: String::from("Lorem ipsum"),
+        sha256_happ: String::from("sha256_happ"),
+        sha256_ui: None,
+        sha256_webhapp: None,
+        distribution_info: String::from("{}"),
+        meta_data: None,
+        network_seed: None,
+        properties: BTreeMap::new(),
+    };
+    conductor.call(zome, "register_applet", applet).await
+}
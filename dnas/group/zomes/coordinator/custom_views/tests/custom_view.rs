@@ -3,14 +3,22 @@
 #![allow(unused_imports)]
 
 use hdk::prelude::*;
-use holochain::{conductor::config::ConductorConfig, sweettest::*};
+use holochain::{
+    conductor::{api::error::ConductorApiResult, config::ConductorConfig},
+    sweettest::*,
+};
 
 use custom_views_integrity::*;
 
-use custom_views::custom_view::UpdateCustomViewInput;
+use custom_views::custom_view::{
+    DeleteCustomViewInput, SetCustomViewActiveVersionInput, UpdateCustomViewInput,
+};
+use moss_helpers::ZomeFnInput;
 
 mod common;
-use common::{create_custom_view, sample_custom_view_1, sample_custom_view_2};
+use common::{
+    create_custom_view, register_sample_applet, sample_custom_view_1, sample_custom_view_2,
+};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn create_custom_view_test() {
@@ -180,7 +188,10 @@ async fn create_and_delete_custom_view() {
         .call(
             &alice_zome,
             "delete_custom_view",
-            original_action_hash.clone(),
+            DeleteCustomViewInput {
+                original_custom_view_hash: original_action_hash.clone(),
+                permission_hash: None,
+            },
         )
         .await;
 
@@ -194,3 +205,164 @@ async fn create_and_delete_custom_view() {
 
     assert!(get_record.is_none());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn custom_view_history_and_active_version() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/group.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("we", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("custom_views");
+    let bob_zome = bobbo.zome("custom_views");
+
+    let sample_1 = sample_custom_view_1(&conductors[0], &alice_zome).await;
+    let sample_2 = sample_custom_view_2(&conductors[0], &alice_zome).await;
+
+    // Alice creates a CustomView and updates it
+    let record: Record = create_custom_view(&conductors[0], &alice_zome, sample_1.clone()).await;
+    let original_action_hash = record.signed_action.hashed.hash.clone();
+    let update_record: Record = conductors[0]
+        .call(
+            &alice_zome,
+            "update_custom_view",
+            UpdateCustomViewInput {
+                previous_custom_view_hash: original_action_hash.clone(),
+                updated_custom_view: sample_2.clone(),
+            },
+        )
+        .await;
+
+    await_consistency(10, [&alice, &bobbo])
+        .await
+        .expect("Failed to await consistency");
+
+    let history: Vec<Record> = conductors[1]
+        .call(
+            &bob_zome,
+            "get_custom_view_history",
+            ZomeFnInput::new(original_action_hash.clone(), Some(false)),
+        )
+        .await;
+    assert_eq!(history, vec![record.clone(), update_record.clone()]);
+
+    // Alice pins the original version
+    let _active_version: Record = conductors[0]
+        .call(
+            &alice_zome,
+            "set_custom_view_active_version",
+            SetCustomViewActiveVersionInput {
+                permission_hash: None,
+                custom_view_hash: original_action_hash.clone(),
+                version_hash: Some(original_action_hash.clone()),
+            },
+        )
+        .await;
+
+    await_consistency(10, [&alice, &bobbo])
+        .await
+        .expect("Failed to await consistency");
+
+    let get_record: Option<Record> = conductors[1]
+        .call(
+            &bob_zome,
+            "get_custom_view",
+            ZomeFnInput::new(original_action_hash.clone(), Some(false)),
+        )
+        .await;
+    assert_eq!(record, get_record.unwrap());
+
+    // Alice unpins the custom view again
+    let _active_version: Record = conductors[0]
+        .call(
+            &alice_zome,
+            "set_custom_view_active_version",
+            SetCustomViewActiveVersionInput {
+                permission_hash: None,
+                custom_view_hash: original_action_hash.clone(),
+                version_hash: None,
+            },
+        )
+        .await;
+
+    await_consistency(10, [&alice, &bobbo])
+        .await
+        .expect("Failed to await consistency");
+
+    let get_record: Option<Record> = conductors[1]
+        .call(
+            &bob_zome,
+            "get_custom_view",
+            ZomeFnInput::new(original_action_hash.clone(), Some(false)),
+        )
+        .await;
+    assert_eq!(update_record, get_record.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn custom_views_bound_to_applet() {
+    // Use prebuilt dna file
+    let dna_path = std::env::current_dir()
+        .unwrap()
+        .join("../../../workdir/group.dna");
+    let dna = SweetDnaFile::from_bundle(&dna_path).await.unwrap();
+
+    // Set up conductors
+    let mut conductors = SweetConductorBatch::from_config(2, ConductorConfig::default()).await;
+    let apps = conductors.setup_app("we", &[dna]).await.unwrap();
+    conductors.exchange_peer_info().await;
+
+    let ((alice,), (bobbo,)) = apps.into_tuples();
+
+    let alice_zome = alice.zome("custom_views");
+    let bob_zome = bobbo.zome("custom_views");
+
+    let applet_hash = register_sample_applet(&conductors[0], &alice.zome("group")).await;
+    let mut sample = sample_custom_view_1(&conductors[0], &alice_zome).await;
+    sample.target = Some(CustomViewTarget::Applet(applet_hash.clone()));
+
+    // Alice creates a CustomView bound to the applet and one without target
+    let record: Record = create_custom_view(&conductors[0], &alice_zome, sample.clone()).await;
+    let sample_2 = sample_custom_view_2(&conductors[0], &alice_zome).await;
+    let record_2: Record = create_custom_view(&conductors[0], &alice_zome, sample_2).await;
+
+    // Custom views cannot be bound to entries that are not Applets
+    let mut sample_3 = sample_custom_view_1(&conductors[0], &alice_zome).await;
+    sample_3.target = Some(CustomViewTarget::Applet(
+        record_2.action().entry_hash().unwrap().clone(),
+    ));
+    let result: ConductorApiResult<Record> = conductors[0]
+        .call_fallible(&alice_zome, "create_custom_view", sample_3)
+        .await;
+    assert!(result.is_err());
+
+    await_consistency(10, [&alice, &bobbo])
+        .await
+        .expect("Failed to await consistency");
+
+    let records: Vec<Record> = conductors[1]
+        .call(
+            &bob_zome,
+            "get_custom_views_for_applet",
+            ZomeFnInput::new(applet_hash, Some(false)),
+        )
+        .await;
+    assert_eq!(records, vec![record]);
+
+    let records: Vec<Record> = conductors[1]
+        .call(
+            &bob_zome,
+            "get_custom_views_for_group_home",
+            ZomeFnInput::new((), Some(false)),
+        )
+        .await;
+    assert!(records.is_empty());
+}
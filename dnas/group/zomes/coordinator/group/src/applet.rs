@@ -1,8 +1,10 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use group_integrity::*;
 use hdk::prelude::*;
-use moss_helpers::ZomeFnInput;
+use moss_helpers::{get_bucketed_targets_page, Page, PaginationInput, ZomeFnInput};
+
+use crate::steward_permission::check_steward_permission_not_revoked;
 
 #[hdk_extern]
 fn hash_applet(applet: Applet) -> ExternResult<EntryHash> {
@@ -20,9 +22,10 @@ fn register_and_join_applet(input: JoinAppletInput) -> ExternResult<EntryHash> {
 fn register_applet(input: Applet) -> ExternResult<EntryHash> {
     let applet_hash = hash_entry(&input)?;
 
+    check_steward_permission_not_revoked(input.permission_hash.clone())?;
     create_entry(EntryTypes::Applet(input.clone()))?;
 
-    let path = Path::from(ALL_APPLETS_ANCHOR);
+    let path = all_applets_bucket_anchor(&applet_hash);
     let anchor_hash = path.path_entry_hash()?;
     create_link(anchor_hash, applet_hash.clone(), LinkTypes::AllApplets, ())?;
 
@@ -43,12 +46,20 @@ fn join_applet(input: JoinAppletInput) -> ExternResult<EntryHash> {
     let applet_hash = hash_entry(&input.applet)?;
 
     // Create a link to your own public key for others to see that you joined that applet
-    // The link also contains the public key that you use in the applet as the tag
+    // The link also contains the public key that you use in the applet as the tag, together
+    // with a signature of the applet public key over your group public key to prove
+    // that both keys belong to you
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let signature = sign(input.joining_pubkey.clone(), my_pubkey.clone())?;
+    let tag = JoinedAgentLinkTag {
+        applet_pubkey: input.joining_pubkey.clone(),
+        signature,
+    };
     create_link(
         applet_hash.clone(),
-        agent_info()?.agent_initial_pubkey,
+        my_pubkey,
         LinkTypes::AppletToJoinedAgent,
-        LinkTag::new(input.joining_pubkey.get_raw_39()),
+        tag.to_link_tag()?,
     )?;
 
     // Store a local copy of the Applet struct to the source chain as a private entry
@@ -90,36 +101,68 @@ fn abandon_applet(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult<()> {
     Ok(())
 }
 
-/// The person who registered the applet to the group may also archive it,
-/// meaning that it won't be discovered by default anymore by agents that have not
-/// installed it yet.
-#[hdk_extern]
-fn archive_applet(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult<()> {
-    let path = Path::from(ALL_APPLETS_ANCHOR);
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ArchiveAppletInput {
+    pub applet_hash: EntryHash,
+    pub permission_hash: Option<ActionHash>,
+    pub reason: Option<String>,
+}
 
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllApplets)?
-            , applet_hash.get_strategy()
-    )?;
+/// The progenitor or stewards may archive an Applet, meaning that it won't be
+/// discovered by default anymore by agents that have not installed it yet.
+#[hdk_extern]
+fn archive_applet(input: ArchiveAppletInput) -> ExternResult<Record> {
+    create_applet_archival(input, true)
+}
 
-    for link in links {
-        if let Some(target_applet_hash) = link.target.into_entry_hash() {
-            if target_applet_hash.eq(&applet_hash.input) {
-                delete_link(link.create_link_hash, GetOptions::local())?;
-            }
-        }
-    }
+#[hdk_extern]
+fn unarchive_applet(input: ArchiveAppletInput) -> ExternResult<Record> {
+    create_applet_archival(input, false)
+}
 
-    Ok(())
+fn create_applet_archival(input: ArchiveAppletInput, archived: bool) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(input.permission_hash.clone())?;
+    let archivals = get_applet_archival_links(&[input.applet_hash.clone()], GetStrategy::Network)?
+        .remove(&input.applet_hash)
+        .unwrap_or_default();
+    let previous_archival = order_applet_archivals(archivals)
+        .pop()
+        .and_then(|(link, _tag)| link.target.into_action_hash());
+    let applet_archival = AppletArchival {
+        permission_hash: input.permission_hash,
+        applet_hash: input.applet_hash.clone(),
+        archived,
+        reason: input.reason,
+        previous_archival,
+    };
+    let tag = AppletArchivalLinkTag::from(&applet_archival).to_link_tag()?;
+    let action_hash = create_entry(&EntryTypes::AppletArchival(applet_archival))?;
+    create_link(
+        all_applets_bucket_anchor(&input.applet_hash).path_entry_hash()?,
+        action_hash.clone(),
+        LinkTypes::AllAppletArchivals,
+        tag,
+    )?;
+    get(action_hash, GetOptions::local())?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        String::from("Could not find the newly created AppletArchival")
+    )))
 }
 
+/// Gets all the AppletArchival records of the given Applet, ordered by their
+/// references to previous AppletArchivals
 #[hdk_extern]
-fn unarchive_applet(applet_hash: EntryHash) -> ExternResult<()> {
-    let path = Path::from(ALL_APPLETS_ANCHOR);
-    let anchor_hash = path.path_entry_hash()?;
-    create_link(anchor_hash, applet_hash.clone(), LinkTypes::AllApplets, ())?;
-
-    Ok(())
+fn get_applet_archivals(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult<Vec<Record>> {
+    let archivals =
+        get_applet_archival_links(&[applet_hash.input.clone()], applet_hash.get_strategy())?
+            .remove(&applet_hash.input)
+            .unwrap_or_default();
+    let get_input: Vec<GetInput> = order_applet_archivals(archivals)
+        .into_iter()
+        .filter_map(|(link, _tag)| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), applet_hash.get_options()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
 }
 
 #[hdk_extern]
@@ -187,19 +230,51 @@ fn get_my_joined_applets() -> ExternResult<Vec<AppletEntryPrivate>> {
 
 #[hdk_extern]
 fn get_group_applets(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>> {
-    let path = Path::from(ALL_APPLETS_ANCHOR);
-
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllApplets)?
-        , input.into()
-    )?;
+    Ok(get_applet_listings(input)?
+        .into_iter()
+        .filter(|listing| !listing.archived)
+        .map(|listing| listing.applet_hash)
+        .collect())
+}
 
-    let entry_hashes = links
+/// Paginated version of get_group_applets. Archived Applets are filtered out of each
+/// page, so pages can contain fewer Applets than the limit even if they are not the
+/// last page.
+#[hdk_extern]
+fn get_group_applets_page(input: ZomeFnInput<PaginationInput>) -> ExternResult<Page<EntryHash>> {
+    let mut links_details_by_target: AllAppletsLinksDetails = HashMap::new();
+    let page = get_bucketed_targets_page(&all_applets_anchors()?, &input.input, |anchor| {
+        collect_all_applets_links_details(
+            anchor,
+            input.get_strategy(),
+            &mut links_details_by_target,
+        )
+    })?;
+
+    let page_applet_hashes: Vec<EntryHash> = page
+        .items
         .into_iter()
-        .filter_map(|link| link.target.into_entry_hash())
+        .filter_map(|target| target.into_entry_hash())
         .collect();
-
-    Ok(entry_hashes)
+    let mut archivals_by_applet =
+        get_applet_archival_links(&page_applet_hashes, input.get_strategy())?;
+
+    let mut applet_hashes = Vec::new();
+    for applet_hash in page_applet_hashes {
+        let Some(details_for_target) = links_details_by_target.remove(&applet_hash) else {
+            continue;
+        };
+        let archivals = archivals_by_applet.remove(&applet_hash).unwrap_or_default();
+        if let Some(listing) = get_applet_listing(applet_hash, details_for_target, archivals) {
+            if !listing.archived {
+                applet_hashes.push(listing.applet_hash);
+            }
+        }
+    }
+    Ok(Page {
+        items: applet_hashes,
+        next_cursor: page.next_cursor,
+    })
 }
 
 /// Gets Applets that are registered in the group but have never been installed in
@@ -215,36 +290,17 @@ fn get_unjoined_applets(
         .map(|ac| ac.public_entry_hash)
         .collect::<Vec<EntryHash>>();
 
-    let path = Path::from(ALL_APPLETS_ANCHOR);
-
-    let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllApplets)?
-          , input.into()
-    )?;
-
-    let applet_infos: Vec<(EntryHash, AgentPubKey, Timestamp)> = links
+    Ok(get_applet_listings(input)?
         .into_iter()
-        .filter(|link| link.target.clone().into_entry_hash().is_some())
-        .map(|link| {
-            (
-                link.target.into_entry_hash().unwrap(),
-                link.author,
-                link.timestamp,
-            )
-        })
-        .collect();
-
-    Ok(applet_infos
-        .into_iter()
-        .filter(|(entry_hash, _author, _timestamp)| {
-            !my_applet_copies_public_hashes.contains(entry_hash)
+        .filter(|listing| {
+            !listing.archived && !my_applet_copies_public_hashes.contains(&listing.applet_hash)
         })
+        .map(|listing| (listing.applet_hash, listing.added_by, listing.timestamp))
         .collect())
 }
 
 /// Gets Applets that are registered in the group but have never been installed in
-/// the local conductor yet and have already been archived by the person that initially
-/// added it to the group
+/// the local conductor yet and have already been archived
 #[hdk_extern]
 fn get_unjoined_archived_applets(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>> {
     let my_applet_copies = get_my_joined_applets(())?;
@@ -263,21 +319,53 @@ fn get_unjoined_archived_applets(input: ZomeFnInput<()>) -> ExternResult<Vec<Ent
 
 #[hdk_extern]
 fn get_archived_applets(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>> {
-    let path = Path::from(ALL_APPLETS_ANCHOR);
+    Ok(get_applet_listings(input)?
+        .into_iter()
+        .filter(|listing| listing.archived)
+        .map(|listing| listing.applet_hash)
+        .collect())
+}
+
+struct AppletListing {
+    applet_hash: EntryHash,
+    added_by: AgentPubKey,
+    timestamp: Timestamp,
+    archived: bool,
+}
 
+type AllAppletsLinksDetails = HashMap<EntryHash, Vec<(CreateLink, Vec<SignedActionHashed>)>>;
+
+type AppletArchivalLinks = Vec<(Link, AppletArchivalLinkTag)>;
+
+/// The anchors from which Applets get linked, with the legacy all_applets anchor last
+fn all_applets_anchors() -> ExternResult<Vec<EntryHash>> {
+    let mut anchors = (0..ALL_APPLETS_BUCKETS)
+        .map(|bucket| all_applets_bucket_anchor_by_index(bucket).path_entry_hash())
+        .collect::<ExternResult<Vec<EntryHash>>>()?;
+    anchors.push(Path::from(ALL_APPLETS_ANCHOR).path_entry_hash()?);
+    Ok(anchors)
+}
+
+/// Collects the details of the AllApplets links of an anchor, including deleted ones,
+/// by their target and returns the targets
+fn collect_all_applets_links_details(
+    anchor: &EntryHash,
+    strategy: GetStrategy,
+    links_details_by_target: &mut AllAppletsLinksDetails,
+) -> ExternResult<Vec<AnyLinkableHash>> {
     let links_details = get_links_details(
-        LinkQuery::new(path.path_entry_hash()?, LinkTypes::AllApplets.try_into_filter().unwrap()),
-        input.into(),
+        LinkQuery::new(
+            anchor.clone(),
+            LinkTypes::AllApplets.try_into_filter().unwrap(),
+        ),
+        strategy,
     )?;
 
-    let mut links_details_by_target: HashMap<
-        EntryHash,
-        Vec<(CreateLink, Vec<SignedActionHashed>)>,
-    > = HashMap::new();
-
+    let mut targets = Vec::new();
     for (create_link, deletes) in links_details.into_inner() {
         if let Action::CreateLink(create_link) = create_link.action() {
             if let Some(target) = create_link.target_address.clone().into_entry_hash() {
+                targets.push(target.clone().into());
                 links_details_by_target
                     .entry(target)
                     .or_insert(vec![])
@@ -285,18 +373,121 @@ fn get_archived_applets(input: ZomeFnInput<()>) -> ExternResult<Vec<EntryHash>>
             }
         }
     }
+    Ok(targets)
+}
 
-    let entry_hashes = links_details_by_target
-        .into_iter()
-        .filter(|(_, details_for_target)| {
-            details_for_target
-                .iter()
-                .all(|(_create, deletes)| deletes.len() > 0)
+/// Gets the AllAppletArchivals links of the given Applets by Applet. The links of
+/// each bucket anchor are fetched once for all the Applets of that bucket.
+fn get_applet_archival_links(
+    applet_hashes: &[EntryHash],
+    strategy: GetStrategy,
+) -> ExternResult<HashMap<EntryHash, AppletArchivalLinks>> {
+    let anchors = applet_hashes
+        .iter()
+        .map(|applet_hash| all_applets_bucket_anchor(applet_hash).path_entry_hash())
+        .collect::<ExternResult<BTreeSet<EntryHash>>>()?;
+
+    let mut archivals_by_applet: HashMap<EntryHash, AppletArchivalLinks> = HashMap::new();
+    for anchor in anchors {
+        let links = get_links(
+            LinkQuery::try_new(anchor, LinkTypes::AllAppletArchivals)?,
+            strategy,
+        )?;
+        for link in links {
+            if let Some(tag) = AppletArchivalLinkTag::from_link_tag(&link.tag) {
+                archivals_by_applet
+                    .entry(tag.applet_hash.clone())
+                    .or_insert(vec![])
+                    .push((link, tag));
+            }
+        }
+    }
+    Ok(archivals_by_applet)
+}
+
+/// Orders the AppletArchivals of an Applet by the number of AppletArchivals that
+/// precede them through their previous_archival references, latest last. Timestamps
+/// only order AppletArchivals that have been created concurrently.
+fn order_applet_archivals(mut archivals: AppletArchivalLinks) -> AppletArchivalLinks {
+    let previous_by_archival: HashMap<ActionHash, Option<ActionHash>> = archivals
+        .iter()
+        .filter_map(|(link, tag)| {
+            let action_hash = link.target.clone().into_action_hash()?;
+            Some((action_hash, tag.previous_archival.clone()))
         })
-        .map(|(target, _)| target)
         .collect();
+    let depth = |tag: &AppletArchivalLinkTag| {
+        let mut depth = 0;
+        let mut previous = tag.previous_archival.clone();
+        while let Some(previous_hash) = previous {
+            depth += 1;
+            previous = previous_by_archival.get(&previous_hash).cloned().flatten();
+        }
+        depth
+    };
+    archivals.sort_by_cached_key(|(link, tag)| {
+        (depth(tag), link.timestamp, link.create_link_hash.clone())
+    });
+    archivals
+}
 
-    Ok(entry_hashes)
+/// Gets all Applets that have ever been registered in the group together with
+/// whether they are currently archived. The archival state is given by the latest
+/// AppletArchival of an Applet. Applets without any AppletArchival are considered
+/// archived if all links to them from the all_applets anchor have been deleted,
+/// which is how Applets used to be archived.
+fn get_applet_listings(input: ZomeFnInput<()>) -> ExternResult<Vec<AppletListing>> {
+    let mut links_details_by_target: AllAppletsLinksDetails = HashMap::new();
+    for anchor in all_applets_anchors()? {
+        collect_all_applets_links_details(
+            &anchor,
+            input.get_strategy(),
+            &mut links_details_by_target,
+        )?;
+    }
+
+    let applet_hashes: Vec<EntryHash> = links_details_by_target.keys().cloned().collect();
+    let mut archivals_by_applet = get_applet_archival_links(&applet_hashes, input.get_strategy())?;
+
+    let mut applet_listings = Vec::new();
+
+    for (applet_hash, details_for_target) in links_details_by_target {
+        let archivals = archivals_by_applet.remove(&applet_hash).unwrap_or_default();
+        if let Some(listing) = get_applet_listing(applet_hash, details_for_target, archivals) {
+            applet_listings.push(listing);
+        }
+    }
+
+    Ok(applet_listings)
+}
+
+fn get_applet_listing(
+    applet_hash: EntryHash,
+    details_for_target: Vec<(CreateLink, Vec<SignedActionHashed>)>,
+    archivals: AppletArchivalLinks,
+) -> Option<AppletListing> {
+    let first_create_link = match details_for_target
+        .iter()
+        .min_by_key(|(create_link, _deletes)| create_link.timestamp)
+    {
+        Some((create_link, _deletes)) => create_link.clone(),
+        None => return None,
+    };
+    let all_links_deleted = details_for_target
+        .iter()
+        .all(|(_create, deletes)| deletes.len() > 0);
+
+    let archived = match order_applet_archivals(archivals).pop() {
+        Some((_link, tag)) => tag.archived,
+        None => all_links_deleted,
+    };
+
+    Some(AppletListing {
+        applet_hash,
+        added_by: first_create_link.author,
+        timestamp: first_create_link.timestamp,
+        archived,
+    })
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -317,7 +508,8 @@ fn get_joined_applet_agents(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult
 
     for link in links {
         let maybe_group_pubkey = AgentPubKey::try_from(link.target).ok();
-        let maybe_applet_pubkey = AgentPubKey::try_from_raw_39(link.tag.as_ref().to_owned()).ok();
+        let maybe_applet_pubkey =
+            JoinedAgentLinkTag::from_link_tag(&link.tag).map(|tag| tag.applet_pubkey);
         match (maybe_group_pubkey, maybe_applet_pubkey) {
             (Some(gk), Some(ak)) => applet_agents.push(AppletAgent {
                 group_pubkey: gk,
@@ -330,6 +522,98 @@ fn get_joined_applet_agents(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult
     Ok(applet_agents)
 }
 
+/// Resolves the public key that an agent uses in the given Applet back to their
+/// public key in the group
+#[hdk_extern]
+fn get_group_pubkey_for_applet_agent(
+    input: ZomeFnInput<(EntryHash, AgentPubKey)>,
+) -> ExternResult<Option<AgentPubKey>> {
+    let (applet_hash, applet_pubkey) = input.input.clone();
+    let links = get_links(
+        LinkQuery::try_new(applet_hash, LinkTypes::AppletToJoinedAgent)?,
+        input.get_strategy(),
+    )?;
+
+    Ok(links.into_iter().find_map(|link| {
+        match JoinedAgentLinkTag::from_link_tag(&link.tag) {
+            Some(tag) if tag.applet_pubkey == applet_pubkey => {
+                AgentPubKey::try_from(link.target).ok()
+            }
+            _ => None,
+        }
+    }))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct DanglingJoinedLink {
+    create_link_hash: ActionHash,
+    applet_hash: EntryHash,
+}
+
+/// Gets the AppletToJoinedAgent links of the calling agent that do not point away
+/// from a valid Applet entry. Such links could be created before validation
+/// required the base to be an Applet.
+#[hdk_extern]
+fn get_my_dangling_joined_links(input: ZomeFnInput<()>) -> ExternResult<Vec<DanglingJoinedLink>> {
+    let joined_agent_link_type: ScopedLinkType = LinkTypes::AppletToJoinedAgent.try_into()?;
+
+    let create_link_records = query(ChainQueryFilter::new().action_type(ActionType::CreateLink))?;
+    let delete_link_records = query(ChainQueryFilter::new().action_type(ActionType::DeleteLink))?;
+    let deleted_link_hashes: Vec<ActionHash> = delete_link_records
+        .into_iter()
+        .filter_map(|record| match record.action() {
+            Action::DeleteLink(delete_link) => Some(delete_link.link_add_address.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut dangling_links = Vec::new();
+
+    for record in create_link_records {
+        let create_link = match record.action() {
+            Action::CreateLink(create_link) => create_link,
+            _ => continue,
+        };
+        if create_link.zome_index != joined_agent_link_type.zome_index
+            || create_link.link_type != joined_agent_link_type.zome_type
+            || deleted_link_hashes.contains(record.action_address())
+        {
+            continue;
+        }
+        let applet_hash = match create_link.base_address.clone().into_entry_hash() {
+            Some(entry_hash) => entry_hash,
+            None => continue,
+        };
+        let is_applet = match get(applet_hash.clone(), input.get_options())? {
+            Some(applet_record) => applet_record
+                .entry()
+                .to_app_option::<Applet>()
+                .ok()
+                .flatten()
+                .is_some(),
+            None => false,
+        };
+        if !is_applet {
+            dangling_links.push(DanglingJoinedLink {
+                create_link_hash: record.action_address().clone(),
+                applet_hash,
+            });
+        }
+    }
+
+    Ok(dangling_links)
+}
+
+/// Deletes the given AppletToJoinedAgent links of the calling agent, as returned
+/// by get_my_dangling_joined_links
+#[hdk_extern]
+fn remove_dangling_joined_links(create_link_hashes: Vec<ActionHash>) -> ExternResult<()> {
+    for create_link_hash in create_link_hashes {
+        delete_link(create_link_hash, GetOptions::local())?;
+    }
+    Ok(())
+}
+
 /// Gets all the agents that abandoned the given Applet through calling abandon_applet
 #[hdk_extern]
 fn get_abandoned_applet_agents(
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use group_integrity::*;
+use hdk::prelude::*;
+use moss_helpers::ZomeFnInput;
+
+use crate::steward_permission::check_steward_permission_not_revoked;
+
+/// Records an upgrade of an Applet to a new happ version. The Applet keeps its
+/// entry hash so that all existing references to it stay valid.
+#[hdk_extern]
+pub fn upgrade_applet(applet_upgrade: AppletUpgrade) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(applet_upgrade.permission_hash.clone())?;
+    let applet_upgrade_hash = create_entry(&EntryTypes::AppletUpgrade(applet_upgrade.clone()))?;
+    create_link(
+        applet_upgrade.applet_hash,
+        applet_upgrade_hash.clone(),
+        LinkTypes::AppletToUpgrades,
+        (),
+    )?;
+    let record = get(applet_upgrade_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from(
+            "Could not find the newly created AppletUpgrade"
+        ))
+    ))?;
+    Ok(record)
+}
+
+/// Gets the chain of upgrades of the given Applet, ordered from the first to the
+/// latest upgrade. The last element describes the latest version of the Applet.
+/// If the chain forks because upgrades have been recorded concurrently, the chain
+/// ends in the upgrade with the most predecessors and, among those, the one with the
+/// highest action hash, the same way get_group_profile_history orders revisions.
+/// Timestamps are not taken into account so that the latest version of the Applet
+/// does not depend on the clocks of the authors.
+#[hdk_extern]
+pub fn get_applet_upgrade_chain(applet_hash: ZomeFnInput<EntryHash>) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        LinkQuery::try_new(applet_hash.input.clone(), LinkTypes::AppletToUpgrades)?,
+        applet_hash.get_strategy(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), applet_hash.get_options()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+
+    let upgrades: HashMap<ActionHash, (Record, AppletUpgrade)> = records
+        .into_iter()
+        .flatten()
+        .filter_map(|record| {
+            record
+                .entry()
+                .to_app_option::<AppletUpgrade>()
+                .ok()
+                .flatten()
+                .map(|upgrade| (record.action_address().clone(), (record, upgrade)))
+        })
+        .collect();
+
+    // Walks back from the given upgrade to the first upgrade. None if any of the
+    // previous upgrades is missing.
+    let chain_to = |action_hash: &ActionHash| -> Option<Vec<Record>> {
+        let mut chain: Vec<Record> = Vec::new();
+        let mut next_hash = Some(action_hash.clone());
+        while let Some(action_hash) = next_hash {
+            let (record, upgrade) = upgrades.get(&action_hash)?;
+            chain.push(record.clone());
+            next_hash = upgrade.previous_upgrade_hash.clone();
+        }
+        chain.reverse();
+        Some(chain)
+    };
+
+    let chain = upgrades
+        .keys()
+        .filter_map(chain_to)
+        .max_by(|chain_a, chain_b| {
+            chain_a.len().cmp(&chain_b.len()).then_with(|| {
+                chain_a
+                    .last()
+                    .map(|record| record.action_address())
+                    .cmp(&chain_b.last().map(|record| record.action_address()))
+            })
+        })
+        .unwrap_or_default();
+
+    Ok(chain)
+}
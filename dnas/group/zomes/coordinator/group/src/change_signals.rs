@@ -0,0 +1,69 @@
+use group_integrity::*;
+use hdk::prelude::*;
+use moss_helpers::ZomeFnInput;
+
+/// Opts in to receive signals about changes to public data in the group from other
+/// members, so that they do not need to be polled for
+#[hdk_extern]
+pub fn subscribe_to_change_signals() -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if get_change_signal_subscribers(ZomeFnInput::new((), Some(true)))?.contains(&my_pub_key) {
+        return Ok(());
+    }
+    create_link(
+        Path::from(CHANGE_SIGNAL_SUBSCRIBERS_ANCHOR).path_entry_hash()?,
+        my_pub_key,
+        LinkTypes::ChangeSignalSubscribers,
+        (),
+    )?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unsubscribe_from_change_signals() -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let links = get_links(
+        LinkQuery::try_new(
+            Path::from(CHANGE_SIGNAL_SUBSCRIBERS_ANCHOR).path_entry_hash()?,
+            LinkTypes::ChangeSignalSubscribers,
+        )?,
+        GetStrategy::Network,
+    )?;
+    for link in links {
+        if link.author == my_pub_key {
+            delete_link(link.create_link_hash, GetOptions::network())?;
+        }
+    }
+    Ok(())
+}
+
+/// Gets all agents that subscribed to change signals
+#[hdk_extern]
+pub fn get_change_signal_subscribers(input: ZomeFnInput<()>) -> ExternResult<Vec<AgentPubKey>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            Path::from(CHANGE_SIGNAL_SUBSCRIBERS_ANCHOR).path_entry_hash()?,
+            LinkTypes::ChangeSignalSubscribers,
+        )?,
+        input.get_strategy(),
+    )?;
+    let mut subscribers: Vec<AgentPubKey> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_agent_pub_key())
+        .collect();
+    subscribers.sort();
+    subscribers.dedup();
+    Ok(subscribers)
+}
+
+/// The agents that changes committed by this agent are sent to. Only locally available
+/// subscriptions are taken into account since this gets called in post_commit.
+pub fn get_remote_change_signal_recipients() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    Ok(
+        get_change_signal_subscribers(ZomeFnInput::new((), Some(true)))?
+            .into_iter()
+            .filter(|agent| agent != &my_pub_key)
+            .collect(),
+    )
+}
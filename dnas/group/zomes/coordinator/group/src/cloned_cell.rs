@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+
 use group_integrity::*;
 use hdk::prelude::*;
 use moss_helpers::ZomeFnInput;
 
+use crate::steward_permission::check_steward_permission_not_revoked;
+
 /// Registers the cloned cell in the group DNA. This is probably mainly useful
 /// for always-online nodes and has implications for privacy in case that there
-/// are cloned cells that are not supposed to be joined by all group members
+/// are cloned cells that are not supposed to be joined by all group members.
+/// Use share_cloned_cell for such cloned cells instead.
 #[hdk_extern]
 fn join_cloned_cell(input: ZomeFnInput<AppletClonedCell>) -> ExternResult<EntryHash> {
     let entry_hash = hash_entry(&input.input)?;
@@ -110,13 +115,137 @@ fn get_unjoined_cloned_cells_for_applet(
         .map(|ac| ac.public_entry_hash)
         .collect::<Vec<EntryHash>>();
 
+    let strategy = applet_hash.get_strategy();
     let all_applet_cloned_cells_entry_hashes =
         get_all_cloned_cell_entry_hashes_for_applet(applet_hash)?;
 
-    Ok(all_applet_cloned_cells_entry_hashes
+    let mut unjoined_cloned_cells = Vec::new();
+    for eh in all_applet_cloned_cells_entry_hashes {
+        if !applet_cloned_cell_public_hashes.contains(&eh)
+            && !is_cloned_cell_archived(eh.clone(), strategy)?
+        {
+            unjoined_cloned_cells.push(eh);
+        }
+    }
+    Ok(unjoined_cloned_cells)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ArchiveClonedCellInput {
+    pub applet_cloned_cell_hash: EntryHash,
+    pub permission_hash: Option<ActionHash>,
+    pub reason: Option<String>,
+}
+
+/// The progenitor or stewards may archive a cloned cell, meaning that it won't be
+/// discovered by default anymore by agents that have not joined it yet.
+#[hdk_extern]
+fn archive_cloned_cell(input: ArchiveClonedCellInput) -> ExternResult<Record> {
+    create_applet_cloned_cell_archival(input, true)
+}
+
+#[hdk_extern]
+fn unarchive_cloned_cell(input: ArchiveClonedCellInput) -> ExternResult<Record> {
+    create_applet_cloned_cell_archival(input, false)
+}
+
+fn create_applet_cloned_cell_archival(
+    input: ArchiveClonedCellInput,
+    archived: bool,
+) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(input.permission_hash.clone())?;
+    let previous_archival = get_cloned_cell_archival_links(
+        input.applet_cloned_cell_hash.clone(),
+        GetStrategy::Network,
+    )?
+    .pop()
+    .and_then(|(link, _tag)| link.target.into_action_hash());
+    let archival = AppletClonedCellArchival {
+        permission_hash: input.permission_hash,
+        applet_cloned_cell_hash: input.applet_cloned_cell_hash.clone(),
+        archived,
+        reason: input.reason,
+        previous_archival,
+    };
+    let tag = AppletClonedCellArchivalLinkTag::from(&archival).to_link_tag()?;
+    let action_hash = create_entry(&EntryTypes::AppletClonedCellArchival(archival))?;
+    create_link(
+        input.applet_cloned_cell_hash,
+        action_hash.clone(),
+        LinkTypes::AppletClonedCellToArchivals,
+        tag,
+    )?;
+    get(action_hash, GetOptions::local())?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        String::from("Could not find the newly created AppletClonedCellArchival")
+    )))
+}
+
+/// Gets the cloned cells of an Applet that are currently archived
+#[hdk_extern]
+fn get_archived_cloned_cells_for_applet(
+    applet_hash: ZomeFnInput<EntryHash>,
+) -> ExternResult<Vec<EntryHash>> {
+    let strategy = applet_hash.get_strategy();
+    let mut archived_cloned_cells = Vec::new();
+    for eh in get_all_cloned_cell_entry_hashes_for_applet(applet_hash)? {
+        if is_cloned_cell_archived(eh.clone(), strategy)? {
+            archived_cloned_cells.push(eh);
+        }
+    }
+    Ok(archived_cloned_cells)
+}
+
+/// A cloned cell is archived if its latest AppletClonedCellArchival says so
+fn is_cloned_cell_archived(
+    applet_cloned_cell_hash: EntryHash,
+    strategy: GetStrategy,
+) -> ExternResult<bool> {
+    let archivals = get_cloned_cell_archival_links(applet_cloned_cell_hash, strategy)?;
+    Ok(archivals.last().is_some_and(|(_link, tag)| tag.archived))
+}
+
+/// Gets the AppletClonedCellToArchivals links of a cloned cell, ordered by the number
+/// of AppletClonedCellArchivals that precede them through their previous_archival
+/// references, latest last. Timestamps only order AppletClonedCellArchivals that have
+/// been created concurrently, the same way as for AppletArchivals.
+fn get_cloned_cell_archival_links(
+    applet_cloned_cell_hash: EntryHash,
+    strategy: GetStrategy,
+) -> ExternResult<Vec<(Link, AppletClonedCellArchivalLinkTag)>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            applet_cloned_cell_hash,
+            LinkTypes::AppletClonedCellToArchivals,
+        )?,
+        strategy,
+    )?;
+    let mut archivals: Vec<(Link, AppletClonedCellArchivalLinkTag)> = links
         .into_iter()
-        .filter(|eh| !applet_cloned_cell_public_hashes.contains(&eh))
-        .collect())
+        .filter_map(|link| {
+            let tag = AppletClonedCellArchivalLinkTag::from_link_tag(&link.tag)?;
+            Some((link, tag))
+        })
+        .collect();
+    let previous_by_archival: HashMap<ActionHash, Option<ActionHash>> = archivals
+        .iter()
+        .filter_map(|(link, tag)| {
+            let action_hash = link.target.clone().into_action_hash()?;
+            Some((action_hash, tag.previous_archival.clone()))
+        })
+        .collect();
+    let depth = |tag: &AppletClonedCellArchivalLinkTag| {
+        let mut depth = 0;
+        let mut previous = tag.previous_archival.clone();
+        while let Some(previous_hash) = previous {
+            depth += 1;
+            previous = previous_by_archival.get(&previous_hash).cloned().flatten();
+        }
+        depth
+    };
+    archivals.sort_by_cached_key(|(link, tag)| {
+        (depth(tag), link.timestamp, link.create_link_hash.clone())
+    });
+    Ok(archivals)
 }
 
 #[hdk_extern]
@@ -170,3 +299,154 @@ fn get_private_applet_cloned_cell_copy(
         .into_iter()
         .find(|copy| copy.public_entry_hash == applet_cloned_cell_entry_hash))
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ShareClonedCellInput {
+    pub applet_cloned_cell: AppletClonedCell,
+    pub recipients: Vec<AgentPubKey>,
+}
+
+/// Shares a cloned cell privately with the given agents only, without registering
+/// it publicly in the group DNA. The AppletClonedCell gets encrypted for each
+/// recipient individually.
+#[hdk_extern]
+fn share_cloned_cell(input: ShareClonedCellInput) -> ExternResult<EntryHash> {
+    let entry_hash = hash_entry(&input.applet_cloned_cell)?;
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+
+    let serialized = SerializedBytes::try_from(input.applet_cloned_cell.clone())
+        .map_err(|e| wasm_error!(e))?;
+
+    for recipient in input.recipients {
+        if recipient == my_pubkey {
+            continue;
+        }
+        let encrypted_applet_cloned_cell = ed_25519_x_salsa20_poly1305_encrypt(
+            my_pubkey.clone(),
+            recipient.clone(),
+            XSalsa20Poly1305Data::from(serialized.bytes().clone()),
+        )?;
+        let action_hash = create_entry(EntryTypes::SharedAppletClonedCell(
+            SharedAppletClonedCell {
+                applet_hash: input.applet_cloned_cell.applet_hash.clone(),
+                recipient: recipient.clone(),
+                encrypted_applet_cloned_cell,
+            },
+        ))?;
+        create_link(
+            recipient,
+            action_hash,
+            LinkTypes::AgentToSharedAppletClonedCells,
+            (),
+        )?;
+    }
+
+    // Store a local copy of the AppletClonedCell to the source chain as a private entry
+    if get_private_applet_cloned_cell_copy(entry_hash.clone())?.is_none() {
+        create_entry(EntryTypes::AppletClonedCellPrivate(
+            AppletClonedCellPrivate {
+                public_entry_hash: entry_hash.clone(),
+                applet_cloned_cell: input.applet_cloned_cell,
+            },
+        ))?;
+    }
+
+    Ok(entry_hash)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClonedCellSharedWithMe {
+    pub shared_by: AgentPubKey,
+    /// ActionHash of the SharedAppletClonedCell entry
+    pub shared_cloned_cell_hash: ActionHash,
+    pub applet_cloned_cell_hash: EntryHash,
+    pub applet_cloned_cell: AppletClonedCell,
+}
+
+/// Gets and decrypts all cloned cells that other agents have shared with me
+/// through share_cloned_cell
+#[hdk_extern]
+fn get_cloned_cells_shared_with_me(
+    input: ZomeFnInput<()>,
+) -> ExternResult<Vec<ClonedCellSharedWithMe>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let links = get_links(
+        LinkQuery::try_new(
+            my_pubkey.clone(),
+            LinkTypes::AgentToSharedAppletClonedCells,
+        )?,
+        input.get_strategy(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), input.get_options()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+
+    let mut shared_cloned_cells = Vec::new();
+    for record in records.into_iter().flatten() {
+        let shared_cloned_cell = match record.entry().to_app_option::<SharedAppletClonedCell>() {
+            Ok(Some(shared_cloned_cell)) => shared_cloned_cell,
+            _ => continue,
+        };
+        let shared_by = record.action().author().clone();
+        let decrypted = match ed_25519_x_salsa20_poly1305_decrypt(
+            my_pubkey.clone(),
+            shared_by.clone(),
+            shared_cloned_cell.encrypted_applet_cloned_cell,
+        ) {
+            Ok(decrypted) => decrypted,
+            Err(_) => continue,
+        };
+        let applet_cloned_cell = match AppletClonedCell::try_from(SerializedBytes::from(
+            UnsafeBytes::from(decrypted.as_ref().to_vec()),
+        )) {
+            Ok(applet_cloned_cell) => applet_cloned_cell,
+            Err(_) => continue,
+        };
+        shared_cloned_cells.push(ClonedCellSharedWithMe {
+            shared_by,
+            shared_cloned_cell_hash: record.action_address().clone(),
+            applet_cloned_cell_hash: hash_entry(&applet_cloned_cell)?,
+            applet_cloned_cell,
+        });
+    }
+
+    Ok(shared_cloned_cells)
+}
+
+/// Removes a cloned cell that has been shared with me from the cloned cells returned
+/// by get_cloned_cells_shared_with_me
+#[hdk_extern]
+fn dismiss_shared_cloned_cell(shared_cloned_cell_hash: ActionHash) -> ExternResult<()> {
+    let links = get_links(
+        LinkQuery::try_new(
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::AgentToSharedAppletClonedCells,
+        )?,
+        GetStrategy::Network,
+    )?;
+    for link in links {
+        if link.target == AnyLinkableHash::from(shared_cloned_cell_hash.clone()) {
+            delete_link(link.create_link_hash, GetOptions::network())?;
+        }
+    }
+    Ok(())
+}
+
+/// Stores a local copy of a cloned cell that has been shared with me as a private
+/// entry to the source chain, without registering it publicly in the group DNA
+#[hdk_extern]
+fn join_shared_cloned_cell(applet_cloned_cell: AppletClonedCell) -> ExternResult<EntryHash> {
+    let entry_hash = hash_entry(&applet_cloned_cell)?;
+    if get_private_applet_cloned_cell_copy(entry_hash.clone())?.is_none() {
+        create_entry(EntryTypes::AppletClonedCellPrivate(
+            AppletClonedCellPrivate {
+                public_entry_hash: entry_hash.clone(),
+                applet_cloned_cell,
+            },
+        ))?;
+    }
+    Ok(entry_hash)
+}
@@ -0,0 +1,115 @@
+use group_integrity::*;
+use hdk::prelude::*;
+use moss_helpers::ZomeFnInput;
+
+use crate::steward_permission::check_steward_permission_not_revoked;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateGroupInvitationInput {
+    pub permission_hash: Option<ActionHash>,
+    pub for_agent: AgentPubKey,
+    pub expiry: Option<Timestamp>,
+    pub max_uses: Option<u32>,
+}
+
+/// Issues an invitation for the given agent and returns the membrane proof that
+/// the invited agent needs to provide at install time to join an invite-only group
+#[hdk_extern]
+pub fn create_group_invitation(
+    input: CreateGroupInvitationInput,
+) -> ExternResult<GroupMembraneProof> {
+    let invitation = GroupInvitation {
+        permission_hash: input.permission_hash,
+        issuer: agent_info()?.agent_initial_pubkey,
+        for_agent: input.for_agent,
+        expiry: input.expiry,
+        max_uses: input.max_uses,
+    };
+    let signature = sign(invitation.issuer.clone(), invitation.clone())?;
+    check_steward_permission_not_revoked(invitation.permission_hash.clone())?;
+    let invitation_hash = create_entry(&EntryTypes::GroupInvitation(invitation.clone()))?;
+    let path = Path::from(ALL_GROUP_INVITATIONS_ANCHOR);
+    let issuer_chain_top = create_link(
+        path.path_entry_hash()?,
+        invitation_hash.clone(),
+        LinkTypes::AllGroupInvitations,
+        (),
+    )?;
+    Ok(GroupMembraneProof {
+        invitation,
+        signature,
+        invitation_hash,
+        issuer_chain_top,
+    })
+}
+
+/// Hands out a new membrane proof for an invitation that the calling agent has issued,
+/// referencing their latest action. Membrane proofs are only accepted for a limited
+/// time after the action they reference, see MAX_MEMBRANE_PROOF_AGE. Fails if the
+/// invitation has been deleted.
+#[hdk_extern]
+pub fn get_group_membrane_proof(invitation_hash: ActionHash) -> ExternResult<GroupMembraneProof> {
+    let Some(Details::Record(details)) = get_details(invitation_hash.clone(), GetOptions::local())?
+    else {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Could not find the GroupInvitation".to_string()
+        )));
+    };
+    if !details.deletes.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The GroupInvitation has been deleted".to_string()
+        )));
+    }
+    let invitation = GroupInvitation::try_from(details.record)?;
+    if invitation.issuer != agent_info()?.agent_initial_pubkey {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the issuer of a GroupInvitation can hand out membrane proofs for it".to_string()
+        )));
+    }
+    let signature = sign(invitation.issuer.clone(), invitation.clone())?;
+    Ok(GroupMembraneProof {
+        invitation,
+        signature,
+        invitation_hash,
+        issuer_chain_top: agent_info()?.chain_head.0,
+    })
+}
+
+/// Gets all invitations that have been issued in this group and have not been deleted
+#[hdk_extern]
+pub fn get_group_invitations(input: ZomeFnInput<()>) -> ExternResult<Vec<Record>> {
+    let path = Path::from(ALL_GROUP_INVITATIONS_ANCHOR);
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupInvitations)?,
+        input.get_strategy(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| GetInput::new(action_hash.into(), input.get_options()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+
+/// Deletes an invitation. Can only be done by the issuer of the invitation or the
+/// progenitor. If the issuer deletes it, membrane proofs for the invitation are
+/// rejected from then on, except for ones that have been handed out before the delete
+/// and are younger than MAX_MEMBRANE_PROOF_AGE.
+#[hdk_extern]
+pub fn delete_group_invitation(invitation_hash: ZomeFnInput<ActionHash>) -> ExternResult<()> {
+    let path = Path::from(ALL_GROUP_INVITATIONS_ANCHOR);
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupInvitations)?,
+        invitation_hash.get_strategy(),
+    )?;
+    for link in links {
+        if let Some(target) = link.target.into_action_hash() {
+            if target.eq(&invitation_hash.input) {
+                delete_link(link.create_link_hash, GetOptions::local())?;
+            }
+        }
+    }
+    delete_entry(invitation_hash.input)?;
+    Ok(())
+}
@@ -1,30 +1,209 @@
 use group_integrity::*;
 use hdk::prelude::*;
 use moss_helpers::ZomeFnInput;
+use std::collections::BTreeSet;
 
-use crate::get_latest_record_from_links;
+use crate::{get_update_chain_revisions, steward_permission::check_steward_permission_not_revoked};
+
+/// Sets the GroupMetaData for a key. If a GroupMetaData exists for the key already,
+/// its latest revision gets updated, otherwise a new GroupMetaData entry is created.
+/// The GroupMetaData must reference the latest GroupMetaDataSchema of the key, which
+/// gets referenced if no schema_hash is provided. Keys without a schema get a String
+/// schema registered first.
 #[hdk_extern]
-pub fn set_group_meta_data(group_meta_data: GroupMetaData) -> ExternResult<Record> {
-    let group_meta_data_hash = create_entry(&EntryTypes::GroupMetaData(group_meta_data.clone()))?;
+pub fn set_group_meta_data(mut group_meta_data: GroupMetaData) -> ExternResult<Record> {
+    let input = ZomeFnInput::new(group_meta_data.name.clone(), Some(false));
+    let current_schema_hash = get_group_meta_data_schema(input.clone())?
+        .map(|schema_record| schema_record.action_address().clone());
+    if let (Some(schema_hash), Some(current_schema_hash)) = (
+        group_meta_data.schema_hash.as_ref(),
+        current_schema_hash.as_ref(),
+    ) {
+        if schema_hash != current_schema_hash {
+            return Err(wasm_error!(WasmErrorInner::Guest(
+                "GroupMetaData must reference the current GroupMetaDataSchema of its key."
+                    .to_string()
+            )));
+        }
+    }
+    if group_meta_data.schema_hash.is_none() {
+        let schema_hash = match current_schema_hash {
+            Some(schema_hash) => schema_hash,
+            None => set_group_meta_data_schema(GroupMetaDataSchema {
+                permission_hash: group_meta_data.permission_hash.clone(),
+                name: group_meta_data.name.clone(),
+                value_type: GroupMetaDataValueType::String,
+                description: None,
+            })?
+            .action_address()
+            .clone(),
+        };
+        group_meta_data.schema_hash = Some(schema_hash);
+    }
+    let history = get_group_meta_data_history(input)?;
+    check_steward_permission_not_revoked(group_meta_data.permission_hash.clone())?;
+    let group_meta_data_hash = match history.last() {
+        Some(latest) => update_entry(latest.action_address().clone(), &group_meta_data)?,
+        None => {
+            let group_meta_data_hash =
+                create_entry(&EntryTypes::GroupMetaData(group_meta_data.clone()))?;
+            let path = group_meta_data_anchor(&group_meta_data.name);
+            create_link(
+                path.path_entry_hash()?,
+                group_meta_data_hash.clone(),
+                LinkTypes::GroupMetaDataToAnchor,
+                (),
+            )?;
+            list_group_meta_data_key(&group_meta_data.name)?;
+            group_meta_data_hash
+        }
+    };
     let record = get(group_meta_data_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created GroupMetaData".to_string())
     ))?;
-    let path = Path::from(group_meta_data.name.as_str());
-    create_link(
-        path.path_entry_hash()?,
-        group_meta_data_hash.clone(),
-        LinkTypes::GroupMetaDataToAnchor,
-        (),
-    )?;
     Ok(record)
 }
 
+/// Gets the latest revision of the GroupMetaData for a key as determined by
+/// get_group_meta_data_history
 #[hdk_extern]
 pub fn get_group_meta_data(input: ZomeFnInput<String>) -> ExternResult<Option<Record>> {
-    let path = Path::from(input.input.as_str());
+    Ok(get_group_meta_data_history(input)?.pop())
+}
+
+/// Gets all revisions of the GroupMetaData for a key, ordered from oldest to newest.
+/// Each record carries the author and the timestamp of the revision. Revisions are
+/// ordered by their position in the update chain as described in
+/// get_update_chain_revisions.
+#[hdk_extern]
+pub fn get_group_meta_data_history(input: ZomeFnInput<String>) -> ExternResult<Vec<Record>> {
+    let path = group_meta_data_anchor(&input.input);
     let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::GroupMetaDataToAnchor)?
-         , input.clone().into()
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::GroupMetaDataToAnchor)?,
+        input.get_strategy(),
+    )?;
+    get_update_chain_revisions(links, input.get_options())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevertGroupMetaDataInput {
+    pub permission_hash: Option<ActionHash>,
+    /// ActionHash of the GroupMetaData revision to revert to
+    pub revision_hash: ActionHash,
+    pub reason: Option<String>,
+}
+
+/// Re-publishes an earlier revision of the GroupMetaData of a key as its latest
+/// revision, recording which revision has been reverted to and why. The revert
+/// updates the latest revision, so it supersedes all revisions in the update chain
+/// regardless of their timestamps.
+#[hdk_extern]
+pub fn revert_group_meta_data(input: RevertGroupMetaDataInput) -> ExternResult<Record> {
+    let record = get(input.revision_hash.clone(), GetOptions::network())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("GroupMetaData revision not found".to_string())
+    ))?;
+    let revision: GroupMetaData = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Revision does not reference a GroupMetaData entry".to_string()
+        )))?;
+    set_group_meta_data(GroupMetaData {
+        permission_hash: input.permission_hash,
+        name: revision.name,
+        data: revision.data,
+        schema_hash: None,
+        revert: Some(GroupMetaDataRevert {
+            revision_hash: input.revision_hash,
+            reason: input.reason,
+        }),
+    })
+}
+
+/// Sets the schema of a GroupMetaData key. If the key has a schema already, its latest
+/// revision gets updated. GroupMetaData that gets set for the key afterwards needs to
+/// reference the new schema and is validated against it.
+#[hdk_extern]
+pub fn set_group_meta_data_schema(schema: GroupMetaDataSchema) -> ExternResult<Record> {
+    let path = group_meta_data_anchor(&schema.name);
+    let links = get_links(
+        LinkQuery::try_new(
+            path.path_entry_hash()?,
+            LinkTypes::GroupMetaDataKeyToSchemas,
+        )?,
+        GetStrategy::Network,
+    )?;
+    let history = get_update_chain_revisions(links, GetOptions::network())?;
+    check_steward_permission_not_revoked(schema.permission_hash.clone())?;
+    let schema_hash = match history.last() {
+        Some(latest) => update_entry(latest.action_address().clone(), &schema)?,
+        None => {
+            let schema_hash = create_entry(&EntryTypes::GroupMetaDataSchema(schema.clone()))?;
+            create_link(
+                path.path_entry_hash()?,
+                schema_hash.clone(),
+                LinkTypes::GroupMetaDataKeyToSchemas,
+                (),
+            )?;
+            list_group_meta_data_key(&schema.name)?;
+            schema_hash
+        }
+    };
+    let record = get(schema_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created GroupMetaDataSchema".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Gets the latest revision of the GroupMetaDataSchema of a key, ordered the same way
+/// as the revisions of the GroupMetaData of a key
+#[hdk_extern]
+pub fn get_group_meta_data_schema(input: ZomeFnInput<String>) -> ExternResult<Option<Record>> {
+    let path = group_meta_data_anchor(&input.input);
+    let links = get_links(
+        LinkQuery::try_new(
+            path.path_entry_hash()?,
+            LinkTypes::GroupMetaDataKeyToSchemas,
+        )?,
+        input.get_strategy(),
+    )?;
+    Ok(get_update_chain_revisions(links, input.get_options())?.pop())
+}
+
+/// Lists the names of all GroupMetaData keys for which data or a schema exists
+#[hdk_extern]
+pub fn list_group_meta_data_keys(input: ZomeFnInput<()>) -> ExternResult<Vec<String>> {
+    let path = Path::from(ALL_GROUP_META_DATA_KEYS_ANCHOR);
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupMetaDataKeys)?,
+        input.get_strategy(),
+    )?;
+    let keys: BTreeSet<String> = links
+        .into_iter()
+        .filter_map(|link| String::from_utf8(link.tag.into_inner()).ok())
+        .collect();
+    Ok(keys.into_iter().collect())
+}
+
+/// Links the anchor of a key from the anchor of all keys if that has not been done yet
+fn list_group_meta_data_key(name: &str) -> ExternResult<()> {
+    let path = Path::from(ALL_GROUP_META_DATA_KEYS_ANCHOR);
+    let links = get_links(
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupMetaDataKeys)?,
+        GetStrategy::Local,
+    )?;
+    if links
+        .iter()
+        .any(|link| link.tag.clone().into_inner() == name.as_bytes())
+    {
+        return Ok(());
+    }
+    create_link(
+        path.path_entry_hash()?,
+        group_meta_data_anchor(name).path_entry_hash()?,
+        LinkTypes::AllGroupMetaDataKeys,
+        LinkTag::new(name),
     )?;
-    get_latest_record_from_links(links, input.into())
+    Ok(())
 }
@@ -2,29 +2,75 @@ use group_integrity::*;
 use hdk::prelude::*;
 use moss_helpers::ZomeFnInput;
 
-use crate::get_latest_record_from_links;
+use crate::{get_update_chain_revisions, steward_permission::check_steward_permission_not_revoked};
+
+/// Sets the group profile. If a group profile exists already, the latest revision
+/// of it gets updated, otherwise a new GroupProfile entry is created.
 #[hdk_extern]
 pub fn set_group_profile(group_profile: GroupProfile) -> ExternResult<Record> {
-    let group_profile_hash = create_entry(&EntryTypes::GroupProfile(group_profile.clone()))?;
+    let history = get_group_profile_history(ZomeFnInput::new((), Some(false)))?;
+    check_steward_permission_not_revoked(group_profile.permission_hash.clone())?;
+    let group_profile_hash = match history.last() {
+        Some(latest) => update_entry(latest.action_address().clone(), &group_profile)?,
+        None => {
+            let group_profile_hash =
+                create_entry(&EntryTypes::GroupProfile(group_profile.clone()))?;
+            let path = Path::from("all_group_profiles");
+            create_link(
+                path.path_entry_hash()?,
+                group_profile_hash.clone(),
+                LinkTypes::AllGroupProfiles,
+                (),
+            )?;
+            group_profile_hash
+        }
+    };
     let record = get(group_profile_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created GroupProfile".to_string())
     ))?;
-    let path = Path::from("all_group_profiles");
-    create_link(
-        path.path_entry_hash()?,
-        group_profile_hash.clone(),
-        LinkTypes::AllGroupProfiles,
-        (),
-    )?;
     Ok(record)
 }
 
+/// Gets the latest revision of the group profile as determined by
+/// get_group_profile_history
 #[hdk_extern]
 pub fn get_group_profile(input: ZomeFnInput<()>) -> ExternResult<Option<Record>> {
+    Ok(get_group_profile_history(input)?.pop())
+}
+
+/// Gets all revisions of the group profile, ordered by their position in the update
+/// chain as described in get_update_chain_revisions
+#[hdk_extern]
+pub fn get_group_profile_history(input: ZomeFnInput<()>) -> ExternResult<Vec<Record>> {
     let path = Path::from("all_group_profiles");
     let links = get_links(
-        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupProfiles)?
-        , input.clone().into()
+        LinkQuery::try_new(path.path_entry_hash()?, LinkTypes::AllGroupProfiles)?,
+        input.get_strategy(),
     )?;
-    get_latest_record_from_links(links, input.into())
+
+    get_update_chain_revisions(links, input.get_options())
+}
+
+/// Stores the icon of the group. Identical icons result in the same entry hash,
+/// so an icon that has been stored before is not stored again.
+#[hdk_extern]
+pub fn create_group_icon(group_icon: GroupIcon) -> ExternResult<EntryHash> {
+    let icon_hash = hash_entry(&group_icon)?;
+    if get(icon_hash.clone(), GetOptions::local())?.is_none() {
+        create_entry(&EntryTypes::GroupIcon(group_icon))?;
+    }
+    Ok(icon_hash)
+}
+
+#[hdk_extern]
+pub fn get_group_icon(icon_hash: ZomeFnInput<EntryHash>) -> ExternResult<Option<GroupIcon>> {
+    match get(icon_hash.input.clone(), icon_hash.get_options())? {
+        Some(record) => record.entry().to_app_option::<GroupIcon>().map_err(|e| {
+            wasm_error!(WasmErrorInner::Guest(format!(
+                "Failed to deserialize GroupIcon from record: {}",
+                e
+            )))
+        }),
+        None => Ok(None),
+    }
 }
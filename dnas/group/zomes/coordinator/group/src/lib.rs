@@ -1,12 +1,16 @@
 pub mod all_group_profiles;
 pub mod applet;
+pub mod applet_upgrade;
+pub mod change_signals;
 pub mod cloned_cell;
+pub mod group_invitation;
 pub mod group_meta_data;
 pub mod group_profile;
 pub mod steward_permission;
 
 use group_integrity::*;
 use hdk::prelude::*;
+use moss_helpers::has_appeared_in_network;
 
 #[hdk_extern]
 pub fn init() -> ExternResult<InitCallbackResult> {
@@ -26,11 +30,163 @@ pub fn init() -> ExternResult<InitCallbackResult> {
 #[serde(tag = "type")]
 pub enum SignalPayload {
     Arbitrary { content: Vec<u8> },
+    Group(GroupSignal),
+    LinkCreated {
+        action: SignedActionHashed,
+        link_type: LinkTypes,
+    },
+    LinkDeleted {
+        action: SignedActionHashed,
+        link_type: LinkTypes,
+    },
+    EntryCreated {
+        action: SignedActionHashed,
+        app_entry: EntryTypes,
+    },
+    EntryUpdated {
+        action: SignedActionHashed,
+        app_entry: EntryTypes,
+        original_app_entry: EntryTypes,
+    },
+    EntryDeleted {
+        action: SignedActionHashed,
+        original_app_entry: EntryTypes,
+    },
+}
+
+/// Structured signal sent between members of the group
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupSignal {
+    /// Random identifier of the signal, e.g. to deduplicate signals
+    pub message_id: String,
+    /// The agent that sent the signal. Always set to the provenance of the remote call
+    /// on the receiving end so that it cannot be spoofed.
+    pub sender: AgentPubKey,
+    /// Topic that receivers can filter by
+    pub topic: String,
+    /// The Applet that the signal is scoped to, if any
+    pub applet_hash: Option<EntryHash>,
+    pub timestamp: Timestamp,
+    pub content: Vec<u8>,
 }
 
 #[hdk_extern(infallible)]
-pub fn post_commit(_committed_actions: Vec<SignedActionHashed>) {
-    ()
+pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
+    let subscribers = match change_signals::get_remote_change_signal_recipients() {
+        Ok(subscribers) => subscribers,
+        Err(err) => {
+            error!("Error getting change signal subscribers: {:?}", err);
+            vec![]
+        }
+    };
+    for action in committed_actions {
+        if let Err(err) = signal_action(action, &subscribers) {
+            error!("Error signaling new action: {:?}", err);
+        }
+    }
+}
+
+/// Emits a signal for the committed action locally and, if it is a change to public
+/// data, sends it to the agents that subscribed to change signals
+fn signal_action(action: SignedActionHashed, subscribers: &[AgentPubKey]) -> ExternResult<()> {
+    let (signal, is_public) = match action.hashed.content.clone() {
+        Action::CreateLink(create_link) => {
+            match LinkTypes::from_type(create_link.zome_index, create_link.link_type) {
+                Ok(Some(link_type)) => (SignalPayload::LinkCreated { action, link_type }, true),
+                _ => return Ok(()),
+            }
+        }
+        Action::DeleteLink(delete_link) => {
+            let record = get(delete_link.link_add_address.clone(), GetOptions::local())?.ok_or(
+                wasm_error!(WasmErrorInner::Guest(
+                    "Failed to fetch CreateLink action".to_string()
+                )),
+            )?;
+            match record.action() {
+                Action::CreateLink(create_link) => {
+                    match LinkTypes::from_type(create_link.zome_index, create_link.link_type) {
+                        Ok(Some(link_type)) => {
+                            (SignalPayload::LinkDeleted { action, link_type }, true)
+                        }
+                        _ => return Ok(()),
+                    }
+                }
+                _ => {
+                    return Err(wasm_error!(WasmErrorInner::Guest(
+                        "Create Link should exist".to_string()
+                    )));
+                }
+            }
+        }
+        Action::Create(_create) => match get_entry_for_action(&action.hashed.hash)? {
+            Some((app_entry, is_public)) => {
+                (SignalPayload::EntryCreated { action, app_entry }, is_public)
+            }
+            None => return Ok(()),
+        },
+        Action::Update(update) => match (
+            get_entry_for_action(&action.hashed.hash)?,
+            get_entry_for_action(&update.original_action_address)?,
+        ) {
+            (Some((app_entry, is_public)), Some((original_app_entry, _))) => (
+                SignalPayload::EntryUpdated {
+                    action,
+                    app_entry,
+                    original_app_entry,
+                },
+                is_public,
+            ),
+            _ => return Ok(()),
+        },
+        Action::Delete(delete) => match get_entry_for_action(&delete.deletes_address)? {
+            Some((original_app_entry, is_public)) => (
+                SignalPayload::EntryDeleted {
+                    action,
+                    original_app_entry,
+                },
+                is_public,
+            ),
+            None => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+    emit_signal(&signal)?;
+    if is_public && !subscribers.is_empty() {
+        let encoded_signal = ExternIO::encode(signal)
+            .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+        send_remote_signal(encoded_signal, subscribers.to_vec())?;
+    }
+    Ok(())
+}
+
+/// Gets the app entry that the action created as well as whether it is public
+fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<(EntryTypes, bool)>> {
+    let record = match get_details(action_hash.clone(), GetOptions::local())? {
+        Some(Details::Record(record_details)) => record_details.record,
+        _ => {
+            return Ok(None);
+        }
+    };
+    let entry = match record.entry().as_option() {
+        Some(entry) => entry,
+        None => {
+            return Ok(None);
+        }
+    };
+    let (zome_index, entry_index, visibility) = match record.action().entry_type() {
+        Some(EntryType::App(AppEntryDef {
+            zome_index,
+            entry_index,
+            visibility,
+        })) => (zome_index, entry_index, visibility),
+        _ => {
+            return Ok(None);
+        }
+    };
+    Ok(
+        EntryTypes::deserialize_from_type(zome_index.clone(), entry_index.clone(), entry)?
+            .map(|app_entry| (app_entry, visibility.is_public())),
+    )
 }
 
 #[hdk_extern]
@@ -39,8 +195,69 @@ pub fn recv_remote_signal(signal: ExternIO) -> ExternResult<()> {
         .decode()
         .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
 
-    match signal_payload.clone() {
-        SignalPayload::Arbitrary { .. } => emit_signal(signal_payload),
+    let sender = call_info()?.provenance;
+    match &signal_payload {
+        SignalPayload::LinkCreated { action, .. }
+        | SignalPayload::LinkDeleted { action, .. }
+        | SignalPayload::EntryCreated { action, .. }
+        | SignalPayload::EntryUpdated { action, .. }
+        | SignalPayload::EntryDeleted { action, .. } => {
+            // Only accept changes that have been authored and signed by the sender and
+            // whose entries are the ones that the action refers to
+            if action.hashed.content.author() != &sender
+                || !verify_signature(
+                    sender.clone(),
+                    action.signature.clone(),
+                    action.hashed.content.clone(),
+                )?
+                || !signal_entries_match_action(&signal_payload)?
+            {
+                return Ok(());
+            }
+        }
+        SignalPayload::Arbitrary { .. } | SignalPayload::Group(_) => {}
+    }
+
+    // Signals from agents that have never appeared in the group are dropped
+    if !has_appeared_in_network(&sender)? {
+        return Ok(());
+    }
+
+    match signal_payload {
+        SignalPayload::Group(mut group_signal) => {
+            group_signal.sender = sender;
+            emit_signal(SignalPayload::Group(group_signal))
+        }
+        _ => emit_signal(signal_payload),
+    }
+}
+
+/// Whether the entries carried by a change signal hash to the entries that the
+/// action of the signal refers to
+fn signal_entries_match_action(signal_payload: &SignalPayload) -> ExternResult<bool> {
+    match signal_payload {
+        SignalPayload::EntryCreated { action, app_entry } => {
+            Ok(action.hashed.content.entry_hash() == Some(&hash_entry(app_entry)?))
+        }
+        SignalPayload::EntryUpdated {
+            action,
+            app_entry,
+            original_app_entry,
+        } => match &action.hashed.content {
+            Action::Update(update) => Ok(update.entry_hash == hash_entry(app_entry)?
+                && update.original_entry_address == hash_entry(original_app_entry)?),
+            _ => Ok(false),
+        },
+        SignalPayload::EntryDeleted {
+            action,
+            original_app_entry,
+        } => match &action.hashed.content {
+            Action::Delete(delete) => {
+                Ok(delete.deletes_entry_address == hash_entry(original_app_entry)?)
+            }
+            _ => Ok(false),
+        },
+        _ => Ok(true),
     }
 }
 
@@ -60,6 +277,77 @@ fn remote_signal_arbitrary(input: ArbitrarySignalPayload) -> ExternResult<()> {
     send_remote_signal(encoded_signal, input.to_agents)
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendGroupSignalInput {
+    pub to_agents: Vec<AgentPubKey>,
+    pub topic: String,
+    pub applet_hash: Option<EntryHash>,
+    pub content: Vec<u8>,
+}
+
+/// Sends a GroupSignal to the given agents and returns its message id
+#[hdk_extern]
+fn send_group_signal(input: SendGroupSignalInput) -> ExternResult<String> {
+    let message_id = random_bytes(16)?
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    let signal_payload = SignalPayload::Group(GroupSignal {
+        message_id: message_id.clone(),
+        sender: call_info()?.provenance,
+        topic: input.topic,
+        applet_hash: input.applet_hash,
+        timestamp: sys_time()?,
+        content: input.content,
+    });
+    let encoded_signal = ExternIO::encode(signal_payload)
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
+    send_remote_signal(encoded_signal, input.to_agents)?;
+    Ok(message_id)
+}
+
+/// Assumes that the passed links have the action hash of an originally created entry as
+/// target and gets all revisions of these entries. Revisions are ordered by their
+/// position in the update chain, i.e. by the number of updates that lead to them from
+/// the originally created entry. Revisions at the same position, which result from
+/// concurrent updates, are ordered by their action hash. Timestamps are deliberately
+/// not taken into account so that the latest revision does not depend on the clocks
+/// of the authors.
+pub fn get_update_chain_revisions(
+    links: Vec<Link>,
+    get_options: GetOptions,
+) -> ExternResult<Vec<Record>> {
+    let mut revisions: Vec<(usize, Record)> = Vec::new();
+    let mut to_visit: Vec<(usize, ActionHash)> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| (0, action_hash))
+        .collect();
+
+    while let Some((depth, action_hash)) = to_visit.pop() {
+        if revisions
+            .iter()
+            .any(|(_, record)| record.action_address() == &action_hash)
+        {
+            continue;
+        }
+        if let Some(Details::Record(details)) = get_details(action_hash, get_options.clone())? {
+            for update in details.updates {
+                to_visit.push((depth + 1, update.hashed.hash));
+            }
+            revisions.push((depth, details.record));
+        }
+    }
+
+    revisions.sort_by(|(depth_a, record_a), (depth_b, record_b)| {
+        depth_a
+            .cmp(depth_b)
+            .then_with(|| record_a.action_address().cmp(record_b.action_address()))
+    });
+
+    Ok(revisions.into_iter().map(|(_, record)| record).collect())
+}
+
 /// Assumes that the passed links has an action hash as target and tries to get the Record
 /// associated to the target of the link with the latest timestamp
 pub fn get_latest_record_from_links(
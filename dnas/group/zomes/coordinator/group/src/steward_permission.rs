@@ -4,6 +4,7 @@ use moss_helpers::ZomeFnInput;
 
 #[hdk_extern]
 pub fn create_steward_permission(steward_permission: StewardPermission) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(steward_permission.permission_hash.clone())?;
     let steward_permission_hash =
         create_entry(&EntryTypes::StewardPermission(steward_permission.clone()))?;
     create_link(
@@ -45,11 +46,182 @@ pub fn get_steward_permission(
     }
 }
 
+/// Approves a non-expiring StewardPermission as a founder in groups that require more
+/// than one founder to issue non-expiring StewardPermissions. The returned signature
+/// needs to be added to the `founder_signatures` of the StewardPermission by whoever
+/// creates it.
+#[hdk_extern]
+pub fn sign_steward_permission(
+    steward_permission: StewardPermission,
+) -> ExternResult<FounderSignature> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !group_dna_properties()?.founders().contains(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only founders can approve StewardPermissions".to_string()
+        )));
+    }
+    let signature = sign(my_pub_key.clone(), steward_permission.signing_payload())?;
+    Ok(FounderSignature {
+        founder: my_pub_key,
+        signature,
+    })
+}
+
+/// Revokes the StewardPermission with the given action hash. The permission and all
+/// permissions issued under it are considered invalid from the timestamp of the
+/// revocation onward. Only the progenitor, founders or stewards with a non-expiring
+/// permission can revoke StewardPermissions.
+#[hdk_extern]
+pub fn revoke_steward_permission(revocation: StewardPermissionRevocation) -> ExternResult<Record> {
+    check_steward_permission_not_revoked(revocation.permission_hash.clone())?;
+    let revocation_hash = create_entry(&EntryTypes::StewardPermissionRevocation(
+        revocation.clone(),
+    ))?;
+    create_link(
+        revocation.revoked_permission_hash.clone(),
+        revocation_hash.clone(),
+        LinkTypes::StewardPermissionToRevocations,
+        (),
+    )?;
+    let record = get(revocation_hash.clone(), GetOptions::local())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(
+            "Could not find the newly created StewardPermissionRevocation".to_string()
+        )
+    ))?;
+    Ok(record)
+}
+
+/// Gets all revocations that have been issued for the StewardPermission with the
+/// given action hash
+#[hdk_extern]
+pub fn get_steward_permission_revocations(
+    steward_permission_hash: ZomeFnInput<ActionHash>,
+) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        LinkQuery::try_new(
+            steward_permission_hash.input.clone(),
+            LinkTypes::StewardPermissionToRevocations,
+        )?,
+        steward_permission_hash.get_strategy(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .map(|action_hash| {
+            GetInput::new(action_hash.into(), steward_permission_hash.get_options())
+        })
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+
+/// Gets the first revocation issued at or before the given timestamp for the
+/// StewardPermission with the given action hash or for any of the permissions it has
+/// been issued under, the same chain that validation checks. Returns the ActionHash of
+/// the revoked permission and of the revocation.
+pub fn get_permission_chain_revocation(
+    permission_hash: ActionHash,
+    now: Timestamp,
+    strategy: GetStrategy,
+) -> ExternResult<Option<(ActionHash, ActionHash)>> {
+    let mut next_permission_hash = Some(permission_hash);
+    while let Some(permission_hash) = next_permission_hash {
+        let links = get_links(
+            LinkQuery::try_new(
+                permission_hash.clone(),
+                LinkTypes::StewardPermissionToRevocations,
+            )?,
+            strategy,
+        )?;
+        if let Some(revocation_hash) = links
+            .into_iter()
+            .filter(|link| link.timestamp <= now)
+            .find_map(|link| link.target.into_action_hash())
+        {
+            return Ok(Some((permission_hash, revocation_hash)));
+        }
+        let get_options = match strategy {
+            GetStrategy::Local => GetOptions::local(),
+            GetStrategy::Network => GetOptions::network(),
+        };
+        let Some(record) = get(permission_hash, get_options)? else {
+            return Ok(None);
+        };
+        let steward_permission = StewardPermission::try_from(record.clone())?;
+        next_permission_hash = match is_agent_a_progenitor(record.action().author().clone())? {
+            true => None,
+            false => steward_permission.permission_hash,
+        };
+    }
+    Ok(None)
+}
+
+/// Records on the source chain of the calling agent that they have taken note of the
+/// revocation of one of their StewardPermissions, unless they already have. From then
+/// on validation rejects their actions based on the revoked permission.
+fn acknowledge_steward_permission_revocation(
+    revoked_permission_hash: ActionHash,
+    revocation_hash: ActionHash,
+) -> ExternResult<()> {
+    let receipt_entry_type: EntryType =
+        UnitEntryTypes::StewardPermissionRevocationReceipt.try_into()?;
+    let receipts = query(
+        ChainQueryFilter::new()
+            .entry_type(receipt_entry_type)
+            .include_entries(true),
+    )?;
+    let acknowledged = receipts.into_iter().any(|record| {
+        StewardPermissionRevocationReceipt::try_from(record)
+            .is_ok_and(|receipt| receipt.revocation_hash == revocation_hash)
+    });
+    if !acknowledged {
+        create_entry(EntryTypes::StewardPermissionRevocationReceipt(
+            StewardPermissionRevocationReceipt {
+                revocation_hash,
+                revoked_permission_hash,
+            },
+        ))?;
+    }
+    Ok(())
+}
+
+/// Refuses to act based on a StewardPermission that has been revoked, or that has
+/// been issued under a revoked permission. Called before every action that is taken
+/// based on a StewardPermission, including actions of other zomes of the group DNA.
+#[hdk_extern]
+pub fn check_steward_permission_not_revoked(
+    permission_hash: Option<ActionHash>,
+) -> ExternResult<()> {
+    let Some(permission_hash) = permission_hash else {
+        return Ok(());
+    };
+    if is_agent_a_progenitor(agent_info()?.agent_initial_pubkey)? {
+        return Ok(());
+    }
+    match get_permission_chain_revocation(permission_hash, sys_time()?, GetStrategy::Network)? {
+        Some(_) => Err(wasm_error!(WasmErrorInner::Guest(
+            "StewardPermission has been revoked.".to_string()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Commits the StewardDeleteAuthorization that a steward needs to commit right
+/// before deleting an entry or link of another agent. Called by the zomes of the
+/// group DNA before such deletes. Progenitors and founders don't need one.
+#[hdk_extern]
+pub fn authorize_steward_delete(input: StewardDeleteAuthorization) -> ExternResult<()> {
+    check_steward_permission_not_revoked(Some(input.permission_hash.clone()))?;
+    create_entry(&EntryTypes::StewardDeleteAuthorization(input))?;
+    Ok(())
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(tag = "type", content = "content")]
 pub enum Accountability {
     Progenitor,
+    /// The capabilities of the steward are defined by the scope of the permission
+    /// contained in the claim
     Steward(StewardPermissionClaim),
     Member,
 }
@@ -78,7 +250,31 @@ pub fn get_my_accountabilities(input: ZomeFnInput<Timestamp>) -> ExternResult<Ve
         .filter(|ac| ac.permission.for_agent == my_pub_key)
         .collect::<Vec<StewardPermissionClaim>>();
 
-    match claims.into_iter().find(|c| c.permission.expiry.is_none()) {
+    let strategy = match input.local {
+        None => GetStrategy::default(),
+        Some(true) => GetStrategy::Local,
+        Some(false) => GetStrategy::Network,
+    };
+    let mut unlimited_claim = None;
+    for claim in claims.into_iter().filter(|c| c.permission.expiry.is_none()) {
+        // Cached claims are not honoured anymore once the permission or any of the
+        // permissions it has been issued under has been revoked
+        match get_permission_chain_revocation(claim.permission_hash.clone(), input.input, strategy)?
+        {
+            Some((revoked_permission_hash, revocation_hash)) => {
+                acknowledge_steward_permission_revocation(
+                    revoked_permission_hash,
+                    revocation_hash,
+                )?;
+            }
+            None => {
+                unlimited_claim = Some(claim);
+                break;
+            }
+        }
+    }
+
+    match unlimited_claim {
         Some(claim) => accs.push(Accountability::Steward(claim)),
         // If no unlimited permission claim is found in source-chain, check the DHT
         None => {
@@ -103,6 +299,45 @@ pub fn get_agent_accountabilities(arg: ZomeFnInput<(AgentPubKey, Timestamp)>) ->
     Ok(accs)
 }
 
+/// Gets the accountabilities that the agent has right now based on the given
+/// StewardPermission. Used by other DNAs to check actions that an agent has taken as
+/// a steward at the time they get read, since the timestamps of such actions are
+/// chosen by their authors. The permission only counts while it is for the agent,
+/// has not expired and neither it nor any of the permissions it has been issued under
+/// has been revoked.
+#[hdk_extern]
+pub fn get_agent_accountabilities_by_permission(
+    arg: ZomeFnInput<(AgentPubKey, Option<ActionHash>)>,
+) -> ExternResult<Vec<Accountability>> {
+    let (agent, permission_hash) = arg.input.clone();
+    let mut accs = Vec::new();
+    if is_agent_a_progenitor(agent.clone())? {
+        accs.push(Accountability::Progenitor);
+    }
+    let Some(permission_hash) = permission_hash else {
+        return Ok(accs);
+    };
+    let Some(record) = get(permission_hash.clone(), arg.get_options())? else {
+        return Ok(accs);
+    };
+    let Ok(permission) = StewardPermission::try_from(record) else {
+        return Ok(accs);
+    };
+    let now = sys_time()?;
+    if permission.for_agent != agent || permission.expiry.is_some_and(|expiry| now > expiry) {
+        return Ok(accs);
+    }
+    let revocation =
+        get_permission_chain_revocation(permission_hash.clone(), now, arg.get_strategy())?;
+    if revocation.is_none() {
+        accs.push(Accountability::Steward(StewardPermissionClaim {
+            permission_hash,
+            permission,
+        }));
+    }
+    Ok(accs)
+}
+
 /// Note: Optimized zome call
 /// Note: Here we are not declaring everyone as progenitor if none is set in the dna properties
 #[hdk_extern]
@@ -110,16 +345,9 @@ pub fn get_all_agents_accountabilities(
     input: ZomeFnInput<Timestamp>,
 ) -> ExternResult<Vec<(AgentPubKey, Accountability)>> {
     let mut result = Vec::new();
-    // Check if there is a single progenitor
-    let dna_properties =
-        GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
-            wasm_error!(WasmErrorInner::Guest(format!(
-                "Failed to deserialize DNA properties: {e}"
-            )))
-        })?;
-    if let Some(progenitor_b64) = dna_properties.progenitor {
-        let progenitor = AgentPubKey::from(progenitor_b64);
-        result.push((progenitor, Accountability::Progenitor));
+    // The progenitor and all founders
+    for founder in group_dna_properties()?.founders() {
+        result.push((founder, Accountability::Progenitor));
     }
     // Get all steward permissions
     let all_permission_links =
@@ -144,20 +372,10 @@ pub fn get_all_agents_accountabilities(
     Ok(result)
 }
 
+/// Founders have the same rights as the progenitor. If neither a progenitor nor
+/// founders are defined, everyone is treated as progenitor.
 pub fn is_agent_a_progenitor(agent: AgentPubKey) -> ExternResult<bool> {
-    let dna_properties =
-        GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
-            wasm_error!(WasmErrorInner::Guest(format!(
-                "Failed to deserialize DNA properties: {e}"
-            )))
-        })?;
-    match dna_properties.progenitor {
-        None => Ok(true),
-        Some(progenitor_b64) => {
-            let progenitor = AgentPubKey::from(progenitor_b64);
-            Ok(agent == progenitor)
-        }
-    }
+    Ok(group_dna_properties()?.is_founder(&agent))
 }
 
 pub fn is_agent_a_steward(
@@ -178,6 +396,17 @@ pub fn is_agent_a_steward(
     for link in agent_permissions {
         let maybe_permission_action_hash = ActionHash::try_from(link.target).ok();
         let Some(permission_action_hash) = maybe_permission_action_hash else { continue };
+        if let Some((revoked_permission_hash, revocation_hash)) =
+            get_permission_chain_revocation(permission_action_hash.clone(), now, options)?
+        {
+            if agent == agent_info()?.agent_initial_pubkey {
+                acknowledge_steward_permission_revocation(
+                    revoked_permission_hash,
+                    revocation_hash,
+                )?;
+            }
+            continue;
+        }
         let maybe_permission_record = get(permission_action_hash, options.into())?;
         let Some(permission_record) = maybe_permission_record else { continue };
         match permission_record.entry().to_app_option::<StewardPermission>() {
@@ -4,6 +4,61 @@
 //!
 //! Read about how to include both this zome and its frontend module in your application [here](https://holochain-open-dev.github.io/peer-status).
 use hdk::prelude::*;
+use moss_helpers::has_appeared_in_network;
+use peer_status_integrity::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Whether an agent is available
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "content")]
+pub enum Availability {
+    Online,
+    Away,
+    DoNotDisturb,
+    /// The agent will be offline from the given time on
+    OfflineAt(Timestamp),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CustomStatus {
+    pub message: String,
+    /// Time after which the custom status should not be displayed anymore
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Structured presence of an agent
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Presence {
+    pub availability: Availability,
+    pub custom_status: Option<CustomStatus>,
+    /// The Applet that the agent currently has focused, only if they choose to share it
+    pub focused_applet: Option<EntryHash>,
+}
+
+/// Maximum number of peers included in the presence digest of a pong
+pub const MAX_PRESENCE_DIGEST_SIZE: usize = 200;
+
+/// Maximum number of characters of the status and the custom status message of a
+/// received ping or pong
+pub const MAX_STATUS_LENGTH: usize = 1000;
+
+/// Minimum time in microseconds between two pings or two pongs of the same peer that
+/// get emitted to the UI. Peers ping every 8 seconds, so this only drops floods.
+pub const MIN_SIGNAL_INTERVAL_MICROS: i64 = 2_000_000;
+
+thread_local! {
+    /// When the last ping and the last pong of each peer got emitted to the UI
+    static LAST_EMITTED_SIGNALS: RefCell<HashMap<(AgentPubKey, bool), Timestamp>> =
+        RefCell::new(HashMap::new());
+}
+
+/// When an agent has last been heard from by the agent sharing the digest
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PeerLastSeen {
+    pub agent: AgentPubKey,
+    pub last_seen: Timestamp,
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
@@ -12,11 +67,18 @@ pub enum SignalPayload {
         from_agent: AgentPubKey,
         status: String,
         tz_utc_offset: Option<i32>,
+        #[serde(default)]
+        presence: Option<Presence>,
     },
     Pong {
         from_agent: AgentPubKey,
         status: String,
         tz_utc_offset: Option<i32>,
+        #[serde(default)]
+        presence: Option<Presence>,
+        /// Peers that the sender has recently heard from
+        #[serde(default)]
+        digest: Vec<PeerLastSeen>,
     },
 }
 
@@ -40,10 +102,122 @@ pub fn recv_remote_signal(signal: ExternIO) -> ExternResult<()> {
         .decode()
         .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
 
-    match signal_payload.clone() {
-        SignalPayload::Ping { .. } => emit_signal(signal_payload),
-        SignalPayload::Pong { .. } => emit_signal(signal_payload),
+    // Oversized signals are dropped before the membership of the caller gets looked up
+    if !is_within_size_limits(&signal_payload) {
+        return Ok(());
+    }
+
+    // from_agent is filled in by the sender and is therefore overwritten with the
+    // actual caller so that agents cannot pretend to be someone else. Members are
+    // looked up in the network only once and are read from the local cache afterwards.
+    let caller = call_info()?.provenance;
+    if !has_appeared_in_network(&caller)? {
+        return Ok(());
+    }
+
+    let is_ping = matches!(signal_payload, SignalPayload::Ping { .. });
+    if is_rate_limited(&caller, is_ping)? {
+        return Ok(());
+    }
+
+    match signal_payload {
+        SignalPayload::Ping {
+            status,
+            tz_utc_offset,
+            presence,
+            ..
+        } => emit_signal(SignalPayload::Ping {
+            from_agent: caller,
+            status,
+            tz_utc_offset,
+            presence,
+        }),
+        SignalPayload::Pong {
+            status,
+            tz_utc_offset,
+            presence,
+            digest,
+            ..
+        } => emit_signal(SignalPayload::Pong {
+            from_agent: caller,
+            status,
+            tz_utc_offset,
+            presence,
+            digest: sanitize_digest(digest)?,
+        }),
+    }
+}
+
+/// Whether a ping or pong of the caller has been emitted less than
+/// MIN_SIGNAL_INTERVAL_MICROS ago. The limit is kept in the memory of the wasm
+/// instance, which the conductor may drop and re-create at any time, so it is
+/// best-effort and only bounds how many signals a peer gets through per instance.
+/// PeerStatusClient additionally drops pings that arrive too frequently.
+fn is_rate_limited(caller: &AgentPubKey, is_ping: bool) -> ExternResult<bool> {
+    let now = sys_time()?;
+    Ok(LAST_EMITTED_SIGNALS.with(|last_emitted| {
+        let mut last_emitted = last_emitted.borrow_mut();
+        let key = (caller.clone(), is_ping);
+        if let Some(last) = last_emitted.get(&key) {
+            if now.as_micros() - last.as_micros() < MIN_SIGNAL_INTERVAL_MICROS {
+                return true;
+            }
+        }
+        last_emitted.insert(key, now);
+        false
+    }))
+}
+
+fn is_within_size_limits(signal_payload: &SignalPayload) -> bool {
+    let (status, presence, digest_size) = match signal_payload {
+        SignalPayload::Ping {
+            status, presence, ..
+        } => (status, presence, 0),
+        SignalPayload::Pong {
+            status,
+            presence,
+            digest,
+            ..
+        } => (status, presence, digest.len()),
+    };
+    let custom_status_length = presence
+        .as_ref()
+        .and_then(|presence| presence.custom_status.as_ref())
+        .map(|custom_status| custom_status.message.chars().count())
+        .unwrap_or(0);
+    status.chars().count() <= MAX_STATUS_LENGTH
+        && custom_status_length <= MAX_STATUS_LENGTH
+        && digest_size <= MAX_PRESENCE_DIGEST_SIZE
+}
+
+/// Drops entries of a received digest that lie in the future, keeps only the latest
+/// entry per agent and limits the digest to the most recently seen peers
+fn sanitize_digest(digest: Vec<PeerLastSeen>) -> ExternResult<Vec<PeerLastSeen>> {
+    let now = sys_time()?;
+    let digest = digest
+        .into_iter()
+        .filter(|peer| peer.last_seen <= now)
+        .collect();
+    Ok(merge_digests(vec![digest], MAX_PRESENCE_DIGEST_SIZE))
+}
+
+/// Merges digests by taking the latest last seen timestamp per agent. The result is
+/// ordered from most to least recently seen.
+fn merge_digests(digests: Vec<Vec<PeerLastSeen>>, max_size: usize) -> Vec<PeerLastSeen> {
+    let mut latest: HashMap<AgentPubKey, Timestamp> = HashMap::new();
+    for peer in digests.into_iter().flatten() {
+        let entry = latest.entry(peer.agent).or_insert(peer.last_seen);
+        if peer.last_seen > *entry {
+            *entry = peer.last_seen;
+        }
     }
+    let mut merged: Vec<PeerLastSeen> = latest
+        .into_iter()
+        .map(|(agent, last_seen)| PeerLastSeen { agent, last_seen })
+        .collect();
+    merged.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    merged.truncate(max_size);
+    merged
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -51,22 +225,48 @@ pub struct PingPayload {
     pub to_agents: Vec<AgentPubKey>,
     pub status: String,
     pub tz_utc_offset: Option<i32>,
+    #[serde(default)]
+    pub presence: Option<Presence>,
+    /// If set, only this many randomly chosen agents of to_agents get pinged. The
+    /// others are learned about via the digests in the pongs.
+    #[serde(default)]
+    pub fanout: Option<u32>,
 }
 
 /// Send a remote signal to the given users to check whether they are online
 /// After this ping is sent, a pong is expected as soon as the agents receive the signal
 #[hdk_extern]
 pub fn ping(input: PingPayload) -> ExternResult<()> {
+    let to_agents = match input.fanout {
+        Some(fanout) => choose_random(input.to_agents, fanout as usize)?,
+        None => input.to_agents,
+    };
     let signal_payload = SignalPayload::Ping {
         from_agent: agent_info()?.agent_initial_pubkey,
         status: input.status,
         tz_utc_offset: input.tz_utc_offset,
+        presence: input.presence,
     };
 
     let encoded_signal = ExternIO::encode(signal_payload)
         .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.into())))?;
 
-    send_remote_signal(encoded_signal, input.to_agents)
+    send_remote_signal(encoded_signal, to_agents)
+}
+
+/// Chooses up to n of the given agents at random
+fn choose_random(mut agents: Vec<AgentPubKey>, n: usize) -> ExternResult<Vec<AgentPubKey>> {
+    if agents.len() <= n {
+        return Ok(agents);
+    }
+    let randomness = random_bytes((n * 4) as u32)?;
+    for (i, chunk) in randomness.chunks_exact(4).enumerate() {
+        let random = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize;
+        let j = i + random % (agents.len() - i);
+        agents.swap(i, j);
+    }
+    agents.truncate(n);
+    Ok(agents)
 }
 
 #[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
@@ -74,6 +274,11 @@ pub struct PongPayload {
     pub to_agents: Vec<AgentPubKey>,
     pub status: String,
     pub tz_utc_offset: Option<i32>,
+    #[serde(default)]
+    pub presence: Option<Presence>,
+    /// Peers that this agent has recently heard from
+    #[serde(default)]
+    pub digest: Vec<PeerLastSeen>,
 }
 
 #[hdk_extern]
@@ -82,6 +287,8 @@ fn pong(input: PongPayload) -> ExternResult<()> {
         from_agent: agent_info()?.agent_initial_pubkey,
         status: input.status,
         tz_utc_offset: input.tz_utc_offset,
+        presence: input.presence,
+        digest: merge_digests(vec![input.digest], MAX_PRESENCE_DIGEST_SIZE),
     };
 
     let encoded_signal = ExternIO::encode(signal_payload)
@@ -89,3 +296,97 @@ fn pong(input: PongPayload) -> ExternResult<()> {
 
     send_remote_signal(encoded_signal, input.to_agents)
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresenceSnapshotInput {
+    /// The digests received in pongs
+    pub digests: Vec<Vec<PeerLastSeen>>,
+}
+
+/// Merges the presence digests received from peers into a single roster with the
+/// latest time that each agent has been seen, ordered from most to least recently seen.
+/// Agents that have not appeared in the network of the group are dropped so that peers
+/// cannot inject arbitrary agents into the roster.
+#[hdk_extern]
+pub fn get_presence_snapshot(input: PresenceSnapshotInput) -> ExternResult<Vec<PeerLastSeen>> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let mut snapshot = Vec::new();
+    for peer in merge_digests(input.digests, usize::MAX) {
+        if peer.agent != my_pub_key && has_appeared_in_network(&peer.agent)? {
+            snapshot.push(peer);
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Publishes a LastSeen record to the DHT so that peers that are offline right now
+/// can still see this agent's recent activity later. Previously published records
+/// get removed.
+#[hdk_extern]
+pub fn publish_last_seen(_: ()) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let previous_links = get_links(
+        LinkQuery::try_new(my_pub_key.clone(), LinkTypes::AgentToLastSeen)?,
+        GetStrategy::Local,
+    )?;
+    let last_seen_hash = create_entry(&EntryTypes::LastSeen(LastSeen {
+        last_seen: sys_time()?,
+    }))?;
+    create_link(my_pub_key, last_seen_hash, LinkTypes::AgentToLastSeen, ())?;
+    for link in previous_links {
+        delete_link(link.create_link_hash, GetOptions::local())?;
+        if let Some(action_hash) = link.target.into_action_hash() {
+            delete_entry(action_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes all LastSeen records of this agent from the DHT
+#[hdk_extern]
+pub fn unpublish_last_seen(_: ()) -> ExternResult<()> {
+    let links = get_links(
+        LinkQuery::try_new(
+            agent_info()?.agent_initial_pubkey,
+            LinkTypes::AgentToLastSeen,
+        )?,
+        GetStrategy::Local,
+    )?;
+    for link in links {
+        delete_link(link.create_link_hash, GetOptions::local())?;
+        if let Some(action_hash) = link.target.into_action_hash() {
+            delete_entry(action_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Gets the latest time that the given agent has published as being last seen, if
+/// they opted in to publish it
+#[hdk_extern]
+pub fn get_last_seen(agent: AgentPubKey) -> ExternResult<Option<Timestamp>> {
+    let links = get_links(
+        LinkQuery::try_new(agent, LinkTypes::AgentToLastSeen)?,
+        GetStrategy::Network,
+    )?;
+    let mut latest: Option<Timestamp> = None;
+    for link in links {
+        let Some(action_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get(action_hash, GetOptions::network())? else {
+            continue;
+        };
+        let Some(last_seen) = record
+            .entry()
+            .to_app_option::<LastSeen>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if latest.map_or(true, |latest| last_seen.last_seen > latest) {
+            latest = Some(last_seen.last_seen);
+        }
+    }
+    Ok(latest)
+}
@@ -1,4 +1,37 @@
 use hdi::prelude::*;
+
+use steward_permissions::{
+    validate_steward_permission, validate_steward_without_permission_hash, StewardCapability,
+};
+
+use crate::{validate_custom_view_target, CustomViewTarget};
+
+pub const ALL_CUSTOM_VIEWS_ANCHOR: &str = "all_custom_views";
+/// Number of anchors that AllCustomViews links are spread across
+pub const ALL_CUSTOM_VIEWS_BUCKETS: u8 = 16;
+
+/// The bucket anchor from which the custom view with the given action hash gets
+/// linked. Custom views used to be linked from the ALL_CUSTOM_VIEWS_ANCHOR directly,
+/// which became a DHT hotspot.
+pub fn all_custom_views_bucket_anchor(custom_view_hash: &ActionHash) -> Path {
+    all_custom_views_bucket_anchor_by_index(
+        custom_view_hash.get_raw_32()[0] % ALL_CUSTOM_VIEWS_BUCKETS,
+    )
+}
+
+pub fn all_custom_views_bucket_anchor_by_index(bucket: u8) -> Path {
+    Path::from(vec![
+        Component::from(ALL_CUSTOM_VIEWS_ANCHOR),
+        Component::from(vec![bucket]),
+    ])
+}
+
+pub const MAX_CUSTOM_VIEW_NAME_LENGTH: usize = 100;
+/// Maximum number of characters of the logo (approx. 200KB)
+pub const MAX_CUSTOM_VIEW_LOGO_SIZE: usize = 300_000;
+/// Maximum number of characters of each of html, js and css
+pub const MAX_CUSTOM_VIEW_CODE_SIZE: usize = 1_000_000;
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct CustomView {
@@ -7,31 +40,158 @@ pub struct CustomView {
     pub html: String,
     pub js: String,
     pub css: String,
+    /// ActionHash of the StewardPermission of the group based on which the custom
+    /// view got approved, if any
+    #[serde(default)]
+    pub permission_hash: Option<ActionHash>,
+    /// Where the custom view gets rendered. Custom views without a target are only
+    /// rendered when explicitly opened.
+    #[serde(default)]
+    pub target: Option<CustomViewTarget>,
 }
+
+/// Rules
+/// 1. The name, logo, html, js and css must not exceed their size limits
+/// 2. If a permission hash is provided, it must point to a valid StewardPermission
+///    of the author
+/// 3. Custom views bound to a target get rendered automatically for all members, so
+///    only stewards with the ManageCustomViews capability or the progenitor can bind
+///    custom views to a target
+/// 4. The target must be valid, see validate_custom_view_target
 pub fn validate_create_custom_view(
-    _action: EntryCreationAction,
-    _custom_view: CustomView,
+    action: EntryCreationAction,
+    custom_view: CustomView,
 ) -> ExternResult<ValidateCallbackResult> {
+    if custom_view.name.chars().count() > MAX_CUSTOM_VIEW_NAME_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The name of a custom view must not be longer than {MAX_CUSTOM_VIEW_NAME_LENGTH} characters."
+        )));
+    }
+    if custom_view.logo.chars().count() > MAX_CUSTOM_VIEW_LOGO_SIZE {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The logo of a custom view must not be larger than {MAX_CUSTOM_VIEW_LOGO_SIZE} characters."
+        )));
+    }
+    for (field, code) in [
+        ("html", &custom_view.html),
+        ("js", &custom_view.js),
+        ("css", &custom_view.css),
+    ] {
+        if code.chars().count() > MAX_CUSTOM_VIEW_CODE_SIZE {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The {field} of a custom view must not be larger than {MAX_CUSTOM_VIEW_CODE_SIZE} characters."
+            )));
+        }
+    }
+    if let Some(target) = &custom_view.target {
+        let target_validation = validate_custom_view_target(target)?;
+        if target_validation != ValidateCallbackResult::Valid {
+            return Ok(target_validation);
+        }
+    }
+    if custom_view.permission_hash.is_some() || custom_view.target.is_some() {
+        return validate_steward_permission(
+            action.author(),
+            action.prev_action(),
+            custom_view.permission_hash,
+            action.timestamp(),
+            true,
+            StewardCapability::ManageCustomViews,
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+
+/// The author of the Create action that the revisions of a custom view originate from
+fn custom_view_creator(action: &EntryCreationAction) -> ExternResult<AgentPubKey> {
+    let mut action_hash = match action {
+        EntryCreationAction::Create(create) => return Ok(create.author.clone()),
+        EntryCreationAction::Update(update) => update.original_action_address.clone(),
+    };
+    loop {
+        let original_action = must_get_action(action_hash)?;
+        match original_action.action() {
+            Action::Create(create) => return Ok(create.author.clone()),
+            Action::Update(update) => action_hash = update.original_action_address.clone(),
+            _ => {
+                return Err(wasm_error!(WasmErrorInner::Guest(
+                    "Revisions of a custom view must originate from a Create action".into()
+                )))
+            }
+        }
+    }
+}
+
+/// Rules
+/// 1. Only the author of the originally created custom view or a steward with the
+///    ManageCustomViews capability can update it.
+///    Stewards need to reference their StewardPermission in the updated custom view.
+/// 2. The target of a custom view cannot be changed
 pub fn validate_update_custom_view(
-    _action: Update,
-    _custom_view: CustomView,
-    _original_action: EntryCreationAction,
-    _original_custom_view: CustomView,
+    action: Update,
+    custom_view: CustomView,
+    original_action: EntryCreationAction,
+    original_custom_view: CustomView,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if custom_view.target != original_custom_view.target {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The target of a custom view cannot be changed.".into(),
+        ));
+    }
+    if action.author == custom_view_creator(&original_action)? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match validate_steward_permission(
+        &action.author,
+        &action.prev_action,
+        custom_view.permission_hash,
+        &action.timestamp,
+        true,
+        StewardCapability::ManageCustomViews,
+    )? {
+        ValidateCallbackResult::Valid => Ok(ValidateCallbackResult::Valid),
+        _ => Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a custom view or a steward can update it.".into(),
+        )),
+    }
 }
+
+/// Rules
+/// 1. Only the author of the originally created custom view or a steward with the
+///    ManageCustomViews capability can delete it. Deletes cannot reference a
+///    StewardPermission, so stewards need to have committed a
+///    StewardDeleteAuthorization for the custom view right before deleting it.
 pub fn validate_delete_custom_view(
-    _action: Delete,
-    _original_action: EntryCreationAction,
+    action: Delete,
+    original_action: EntryCreationAction,
     _original_custom_view: CustomView,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if action.author == custom_view_creator(&original_action)? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match validate_steward_without_permission_hash(
+        &action.author,
+        &action.prev_action,
+        &action.deletes_address,
+        &action.timestamp,
+        true,
+        StewardCapability::ManageCustomViews,
+    )? {
+        ValidateCallbackResult::Valid => Ok(ValidateCallbackResult::Valid),
+        _ => Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a custom view or a steward can delete it.".into(),
+        )),
+    }
 }
+
+/// Rules
+/// 1. Link must point to a valid CustomView entry
+/// 2. Link must point away from the all_custom_views anchor or the bucket anchor of
+///    the CustomView
+/// 3. The creator of the link must be the one that created the CustomView entry
 pub fn validate_create_link_all_custom_views(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -39,6 +199,19 @@ pub fn validate_create_link_all_custom_views(
     // let action_hash = ActionHash::from(target_address);
     let action_hash =
         ActionHash::try_from(target_address).map_err(|e| wasm_error!(WasmErrorInner::from(e)))?;
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    if Path::from(ALL_CUSTOM_VIEWS_ANCHOR).path_entry_hash()? != base_address_entry_hash
+        && all_custom_views_bucket_anchor(&action_hash).path_entry_hash()?
+            != base_address_entry_hash
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllCustomViews link is not pointing away from the correct anchor".into(),
+        ));
+    }
     let record = must_get_valid_record(action_hash)?;
     let _custom_view: crate::CustomView = record
         .entry()
@@ -47,7 +220,12 @@ pub fn validate_create_link_all_custom_views(
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Linked action must reference an entry"
         ))))?;
-    // TODO: add the appropriate validation rules
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a CustomView entry can link it from the AllCustomViews anchor"
+                .into(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_all_custom_views(
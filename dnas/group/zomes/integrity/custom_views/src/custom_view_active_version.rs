@@ -0,0 +1,147 @@
+use hdi::prelude::*;
+
+use steward_permissions::{validate_steward_permission, StewardCapability};
+
+use crate::CustomView;
+
+/// Pins the version of a custom view that members render. Pinned versions keep
+/// being rendered even if the custom view gets updated afterwards, until a steward
+/// pins another version or unpins it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CustomViewActiveVersion {
+    /// ActionHash of the StewardPermission based on which the version gets pinned
+    pub permission_hash: Option<ActionHash>,
+    /// ActionHash of the Create action of the custom view
+    pub custom_view_hash: ActionHash,
+    /// ActionHash of the revision of the custom view that is pinned. None unpins the
+    /// custom view, i.e. its latest revision gets rendered again.
+    pub version_hash: Option<ActionHash>,
+}
+
+/// Rules
+/// 1. The custom view hash must point to the Create action of a CustomView entry
+/// 2. The version hash must point to a revision of that custom view
+/// 3. Only stewards with the ManageCustomViews capability or the progenitor can pin
+///    versions of custom views
+pub fn validate_create_custom_view_active_version(
+    action: EntryCreationAction,
+    active_version: CustomViewActiveVersion,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(active_version.custom_view_hash.clone())?;
+    if !matches!(record.action(), Action::Create(_)) || CustomView::try_from(record).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "custom_view_hash does not point to the Create action of a CustomView entry.".into(),
+        ));
+    }
+    if let Some(version_hash) = active_version.version_hash.clone() {
+        let mut action_hash = version_hash.clone();
+        loop {
+            let version_action = must_get_action(action_hash.clone())?;
+            match version_action.action() {
+                Action::Update(update) => action_hash = update.original_action_address.clone(),
+                Action::Create(_) => break,
+                _ => {
+                    return Ok(ValidateCallbackResult::Invalid(
+                        "version_hash does not point to a revision of a CustomView entry.".into(),
+                    ))
+                }
+            }
+        }
+        if action_hash != active_version.custom_view_hash {
+            return Ok(ValidateCallbackResult::Invalid(
+                "version_hash does not point to a revision of the custom view.".into(),
+            ));
+        }
+        if CustomView::try_from(must_get_valid_record(version_hash)?).is_err() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "version_hash does not point to a revision of a CustomView entry.".into(),
+            ));
+        }
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        active_version.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::ManageCustomViews,
+    )
+}
+pub fn validate_update_custom_view_active_version(
+    _action: Update,
+    _active_version: CustomViewActiveVersion,
+    _original_action: EntryCreationAction,
+    _original_active_version: CustomViewActiveVersion,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CustomViewActiveVersion entries cannot be updated",
+    )))
+}
+pub fn validate_delete_custom_view_active_version(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_active_version: CustomViewActiveVersion,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CustomViewActiveVersion entries cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point from the Create action of a custom view to a
+///    CustomViewActiveVersion entry
+/// 2. The CustomViewActiveVersion must be for the custom view that the link is
+///    pointing away from
+/// 3. The creator of the link must be the one that created the CustomViewActiveVersion
+pub fn validate_create_link_custom_view_to_active_versions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let custom_view_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link base is not an action hash".to_string()
+            )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let active_version: CustomViewActiveVersion = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a CustomViewActiveVersion entry".to_string()
+        )))?;
+
+    if active_version.custom_view_hash != custom_view_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to a CustomViewActiveVersion of a different custom view".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a CustomViewActiveVersion entry can create a link to it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_custom_view_to_active_versions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "CustomViewToActiveVersions links cannot be deleted",
+    )))
+}
@@ -0,0 +1,158 @@
+use hdi::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::{CustomView, MAX_CUSTOM_VIEW_NAME_LENGTH};
+
+pub const CUSTOM_VIEW_TARGETS_ANCHOR_ROOT: &str = "custom_view_targets";
+
+/// The place in which a custom view gets rendered
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "content")]
+pub enum CustomViewTarget {
+    /// The home view of the group
+    GroupHome,
+    /// The main view of an Applet, identified by the entry hash of its Applet entry
+    Applet(EntryHash),
+    /// Assets of a given type of an Applet, e.g. one of its creatables
+    AssetType {
+        applet_hash: EntryHash,
+        name: String,
+    },
+}
+
+impl CustomViewTarget {
+    /// The anchor from which custom views bound to this target get linked
+    pub fn anchor(&self) -> Path {
+        let mut components = vec![Component::from(CUSTOM_VIEW_TARGETS_ANCHOR_ROOT)];
+        match self {
+            CustomViewTarget::GroupHome => components.push(Component::from("group_home")),
+            CustomViewTarget::Applet(applet_hash) => {
+                components.push(Component::from("applet"));
+                components.push(Component::from(applet_hash.get_raw_39().to_vec()));
+            }
+            CustomViewTarget::AssetType { applet_hash, name } => {
+                components.push(Component::from("asset_type"));
+                components.push(Component::from(applet_hash.get_raw_39().to_vec()));
+                components.push(Component::from(name.as_str()));
+            }
+        }
+        Path::from(components)
+    }
+}
+
+/// The subset of an Applet entry of the group zome that is required to tell it apart
+/// from other entries. Mirrors the Applet of the group zome.
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct Applet {
+    custom_name: String,
+    sha256_happ: String,
+    distribution_info: String,
+    properties: BTreeMap<String, serde::de::IgnoredAny>,
+}
+
+/// Rules
+/// 1. The applet hash of Applet and AssetType targets must point to an Applet entry
+/// 2. The asset type of AssetType targets must be between 1 and
+///    MAX_CUSTOM_VIEW_NAME_LENGTH characters long
+pub fn validate_custom_view_target(
+    target: &CustomViewTarget,
+) -> ExternResult<ValidateCallbackResult> {
+    let applet_hash = match target {
+        CustomViewTarget::GroupHome => return Ok(ValidateCallbackResult::Valid),
+        CustomViewTarget::Applet(applet_hash) => applet_hash,
+        CustomViewTarget::AssetType { applet_hash, name } => {
+            if name.is_empty() || name.chars().count() > MAX_CUSTOM_VIEW_NAME_LENGTH {
+                return Ok(ValidateCallbackResult::Invalid(format!(
+                    "The asset type of a custom view target must be between 1 and {MAX_CUSTOM_VIEW_NAME_LENGTH} characters long."
+                )));
+            }
+            applet_hash
+        }
+    };
+    let entry = must_get_entry(applet_hash.clone())?;
+    let is_applet = match entry.content {
+        Entry::App(bytes) => {
+            holochain_serialized_bytes::decode::<_, Applet>(bytes.into_sb().bytes()).is_ok()
+        }
+        _ => false,
+    };
+    if !is_applet {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The applet hash of a custom view target does not point to an Applet entry.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Link must point to the Create action of a CustomView entry
+/// 2. Link must point away from the anchor of the target of the CustomView
+/// 3. The creator of the link must be the one that created the CustomView entry
+pub fn validate_create_link_custom_view_target_to_custom_views(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    if !matches!(record.action(), Action::Create(_)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CustomViewTargetToCustomViews links must point to the Create action of a CustomView"
+                .into(),
+        ));
+    }
+    let custom_view: CustomView = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a CustomView entry".to_string()
+        )))?;
+
+    let Some(target) = custom_view.target else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CustomView is not bound to any target".into(),
+        ));
+    };
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    if target.anchor().path_entry_hash()? != base_address_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CustomViewTargetToCustomViews link is not pointing away from the anchor of the target of the CustomView".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a CustomView entry can link it from its target".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the creator of the link can delete it
+pub fn validate_delete_link_custom_view_target_to_custom_views(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a CustomViewTargetToCustomViews link can delete it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,5 +1,9 @@
 pub mod custom_view;
 pub use custom_view::*;
+pub mod custom_view_active_version;
+pub use custom_view_active_version::*;
+pub mod custom_view_target;
+pub use custom_view_target::*;
 use hdi::prelude::*;
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -7,11 +11,14 @@ use hdi::prelude::*;
 #[unit_enum(UnitEntryTypes)]
 pub enum EntryTypes {
     CustomView(CustomView),
+    CustomViewActiveVersion(CustomViewActiveVersion),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
 pub enum LinkTypes {
     AllCustomViews,
+    CustomViewToActiveVersions,
+    CustomViewTargetToCustomViews,
 }
 #[hdk_extern]
 pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
@@ -23,15 +30,20 @@ pub fn validate_agent_joining(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Valid)
 }
-// TODO: fix name of this function
 #[hdk_extern]
-pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
     match op.flattened::<EntryTypes, LinkTypes>()? {
         FlatOp::StoreEntry(store_entry) => match store_entry {
             OpEntry::CreateEntry { app_entry, action } => match app_entry {
                 EntryTypes::CustomView(custom_view) => {
                     validate_create_custom_view(EntryCreationAction::Create(action), custom_view)
                 }
+                EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                    validate_create_custom_view_active_version(
+                        EntryCreationAction::Create(action),
+                        custom_view_active_version,
+                    )
+                }
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -39,6 +51,12 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::CustomView(custom_view) => {
                     validate_create_custom_view(EntryCreationAction::Update(action), custom_view)
                 }
+                EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                    validate_create_custom_view_active_version(
+                        EntryCreationAction::Update(action),
+                        custom_view_active_version,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -74,6 +92,25 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_custom_view,
                         )
                     }
+                    EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_custom_view_active_version =
+                            match CustomViewActiveVersion::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get CustomViewActiveVersion from Record: {e:?}"
+                                )));
+                                }
+                            };
+                        validate_update_custom_view_active_version(
+                            action,
+                            custom_view_active_version,
+                            original_create_action,
+                            original_custom_view_active_version,
+                        )
+                    }
                 }
             }
             _ => Ok(ValidateCallbackResult::Valid),
@@ -121,6 +158,13 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::CustomView(custom_view) => {
                     validate_delete_custom_view(delete_entry.action, original_action, custom_view)
                 }
+                EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                    validate_delete_custom_view_active_version(
+                        delete_entry.action,
+                        original_action,
+                        custom_view_active_version,
+                    )
+                }
             }
         }
         FlatOp::RegisterCreateLink {
@@ -133,6 +177,22 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::AllCustomViews => {
                 validate_create_link_all_custom_views(action, base_address, target_address, tag)
             }
+            LinkTypes::CustomViewToActiveVersions => {
+                validate_create_link_custom_view_to_active_versions(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::CustomViewTargetToCustomViews => {
+                validate_create_link_custom_view_target_to_custom_views(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
         },
         FlatOp::RegisterDeleteLink {
             link_type,
@@ -149,12 +209,36 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
+            LinkTypes::CustomViewToActiveVersions => {
+                validate_delete_link_custom_view_to_active_versions(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::CustomViewTargetToCustomViews => {
+                validate_delete_link_custom_view_target_to_custom_views(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
         },
         FlatOp::StoreRecord(store_record) => match store_record {
             OpRecord::CreateEntry { app_entry, action } => match app_entry {
                 EntryTypes::CustomView(custom_view) => {
                     validate_create_custom_view(EntryCreationAction::Create(action), custom_view)
                 }
+                EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                    validate_create_custom_view_active_version(
+                        EntryCreationAction::Create(action),
+                        custom_view_active_version,
+                    )
+                }
             },
             OpRecord::UpdateEntry {
                 original_action_hash,
@@ -206,6 +290,40 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                             Ok(result)
                         }
                     }
+                    EntryTypes::CustomViewActiveVersion(custom_view_active_version) => {
+                        let result = validate_create_custom_view_active_version(
+                            EntryCreationAction::Update(action.clone()),
+                            custom_view_active_version.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_custom_view_active_version: Option<
+                                CustomViewActiveVersion,
+                            > = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_custom_view_active_version =
+                                match original_custom_view_active_version {
+                                    Some(custom_view_active_version) => custom_view_active_version,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                            validate_update_custom_view_active_version(
+                                action,
+                                custom_view_active_version,
+                                original_action,
+                                original_custom_view_active_version,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
                 }
             }
             OpRecord::DeleteEntry {
@@ -265,6 +383,13 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryTypes::CustomView(original_custom_view) => {
                         validate_delete_custom_view(action, original_action, original_custom_view)
                     }
+                    EntryTypes::CustomViewActiveVersion(original_custom_view_active_version) => {
+                        validate_delete_custom_view_active_version(
+                            action,
+                            original_action,
+                            original_custom_view_active_version,
+                        )
+                    }
                 }
             }
             OpRecord::CreateLink {
@@ -277,6 +402,22 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                 LinkTypes::AllCustomViews => {
                     validate_create_link_all_custom_views(action, base_address, target_address, tag)
                 }
+                LinkTypes::CustomViewToActiveVersions => {
+                    validate_create_link_custom_view_to_active_versions(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CustomViewTargetToCustomViews => {
+                    validate_create_link_custom_view_target_to_custom_views(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             },
             OpRecord::DeleteLink {
                 original_action_hash,
@@ -309,6 +450,24 @@ pub fn validatee(op: Op) -> ExternResult<ValidateCallbackResult> {
                         create_link.target_address,
                         create_link.tag,
                     ),
+                    LinkTypes::CustomViewToActiveVersions => {
+                        validate_delete_link_custom_view_to_active_versions(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::CustomViewTargetToCustomViews => {
+                        validate_delete_link_custom_view_target_to_custom_views(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
                 }
             }
             OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -1,8 +1,24 @@
-use crate::{validate_steward_permission, GroupDnaProperties};
+use crate::{group_dna_properties, validate_steward_permission, StewardCapability};
 use hdi::prelude::*;
 use std::collections::BTreeMap;
 
 pub const ALL_APPLETS_ANCHOR: &str = "ALL_APPLETS";
+/// Number of anchors that AllApplets links are spread across
+pub const ALL_APPLETS_BUCKETS: u8 = 16;
+
+/// The bucket anchor from which the given Applet gets linked. Applets used to be
+/// linked from the ALL_APPLETS_ANCHOR directly, which became a DHT hotspot.
+pub fn all_applets_bucket_anchor(applet_hash: &EntryHash) -> Path {
+    all_applets_bucket_anchor_by_index(applet_hash.get_raw_32()[0] % ALL_APPLETS_BUCKETS)
+}
+
+pub fn all_applets_bucket_anchor_by_index(bucket: u8) -> Path {
+    Path::from(vec![
+        Component::from(ALL_APPLETS_ANCHOR),
+        Component::from(vec![bucket]),
+    ])
+}
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct Applet {
@@ -23,9 +39,11 @@ pub fn validate_create_applet(
 ) -> ExternResult<ValidateCallbackResult> {
     validate_steward_permission(
         action.author(),
+        action.prev_action(),
         applet.permission_hash,
         action.timestamp(),
         true,
+        StewardCapability::ManageApplets,
     )
 }
 pub fn validate_update_applet(
@@ -48,7 +66,8 @@ pub fn validate_delete_applet(
     )))
 }
 /// Rules
-/// 1. Link must point away from the all_applets anchor
+/// 1. Link must point away from the all_applets anchor or the bucket anchor of the
+///    Applet that it points to
 /// 2. Link must point to an entry hash
 pub fn validate_create_link_all_applets(
     _action: CreateLink,
@@ -56,12 +75,16 @@ pub fn validate_create_link_all_applets(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    if let None = target_address.into_entry_hash() {
-        return Ok(ValidateCallbackResult::Invalid(
-            "Target address is not an entry hash.".into(),
-        ));
-    }
-    // Check that base address is pointing away from the all_applets anchor
+    let applet_hash = match target_address.into_entry_hash() {
+        Some(applet_hash) => applet_hash,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Target address is not an entry hash.".into(),
+            ))
+        }
+    };
+    // Check that base address is pointing away from the all_applets anchor or the
+    // bucket anchor of the Applet
     match base_address.into_entry_hash() {
         None => {
             return Ok(ValidateCallbackResult::Invalid(
@@ -70,7 +93,9 @@ pub fn validate_create_link_all_applets(
         }
         Some(eh) => {
             let path = Path::from(ALL_APPLETS_ANCHOR);
-            if path.path_entry_hash()? != eh {
+            if path.path_entry_hash()? != eh
+                && all_applets_bucket_anchor(&applet_hash).path_entry_hash()? != eh
+            {
                 return Ok(ValidateCallbackResult::Invalid(
                     "AllApplets link is not pointing away from the correct anchor".into(),
                 ));
@@ -82,8 +107,11 @@ pub fn validate_create_link_all_applets(
 
 /// Rules
 /// 1. Links can only be removed by the agent that originally created the link
-///    or the group's progenitor. This is due to a lack of the ability to pass
+///    or the group's progenitor or founders. This is due to a lack of the ability to pass
 ///    a permission hash along with a delete link action.
+///
+/// Deleting these links is how Applets used to be archived. Applets are now archived
+/// by creating an AppletArchival entry which carries a permission hash instead.
 pub fn validate_delete_link_all_applets(
     action: DeleteLink,
     _original_action: CreateLink,
@@ -91,21 +119,8 @@ pub fn validate_delete_link_all_applets(
     target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let dna_properties =
-        GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
-            wasm_error!(WasmErrorInner::Guest(format!(
-                "Failed to deserialize DNA properties: {e}"
-            )))
-        })?;
-
-    match dna_properties.progenitor {
-        Some(progenitor_b64) => {
-            let progenitor = AgentPubKey::from(progenitor_b64);
-            if progenitor == action.author {
-                return Ok(ValidateCallbackResult::Valid);
-            }
-        }
-        None => return Ok(ValidateCallbackResult::Valid),
+    if group_dna_properties()?.is_founder(&action.author) {
+        return Ok(ValidateCallbackResult::Valid);
     }
 
     let action_hash = target
@@ -125,8 +140,10 @@ pub fn validate_delete_link_all_applets(
 
     validate_steward_permission(
         &action.author,
+        &action.prev_action,
         applet.permission_hash,
         &action.timestamp,
         true,
+        StewardCapability::ManageApplets,
     )
 }
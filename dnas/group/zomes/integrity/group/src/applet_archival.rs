@@ -0,0 +1,173 @@
+use hdi::prelude::*;
+
+use crate::{all_applets_bucket_anchor, validate_steward_permission, Applet, StewardCapability};
+
+/// Records that an Applet has been archived or unarchived. Archived Applets won't be
+/// discovered by default anymore by agents that have not installed them yet.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AppletArchival {
+    /// ActionHash of the StewardPermission based on which the Applet gets (un)archived
+    pub permission_hash: Option<ActionHash>,
+    pub applet_hash: EntryHash,
+    /// true if the Applet gets archived, false if it gets unarchived
+    pub archived: bool,
+    pub reason: Option<String>,
+    /// ActionHash of the latest AppletArchival of the Applet known to the author.
+    /// AppletArchivals are ordered by these references instead of by their timestamps.
+    #[serde(default)]
+    pub previous_archival: Option<ActionHash>,
+}
+
+/// Tag of an AllAppletArchivals link. Carries the archival state so that the current
+/// state of the Applets of a bucket can be read from the links alone.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+pub struct AppletArchivalLinkTag {
+    pub applet_hash: EntryHash,
+    pub archived: bool,
+    pub previous_archival: Option<ActionHash>,
+}
+
+impl AppletArchivalLinkTag {
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let serialized = SerializedBytes::try_from(self.clone()).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(serialized.bytes().clone()))
+    }
+
+    pub fn from_link_tag(tag: &LinkTag) -> Option<AppletArchivalLinkTag> {
+        AppletArchivalLinkTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.0.clone())))
+            .ok()
+    }
+}
+
+impl From<&AppletArchival> for AppletArchivalLinkTag {
+    fn from(applet_archival: &AppletArchival) -> Self {
+        AppletArchivalLinkTag {
+            applet_hash: applet_archival.applet_hash.clone(),
+            archived: applet_archival.archived,
+            previous_archival: applet_archival.previous_archival.clone(),
+        }
+    }
+}
+
+/// Rules
+/// 1. The applet hash must point to a valid Applet entry
+/// 2. The previous archival must point to an AppletArchival of the same Applet
+/// 3. Only agents with a StewardPermission to manage applets or the progenitor can
+///    (un)archive Applets
+pub fn validate_create_applet_archival(
+    action: EntryCreationAction,
+    applet_archival: AppletArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(applet_archival.applet_hash.clone())?;
+    if Applet::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "applet_hash does not point to an Applet entry.".into(),
+        ));
+    }
+    if let Some(previous_archival_hash) = applet_archival.previous_archival.clone() {
+        let record = must_get_valid_record(previous_archival_hash)?;
+        match AppletArchival::try_from(record) {
+            Ok(previous_archival)
+                if previous_archival.applet_hash == applet_archival.applet_hash => {}
+            _ => {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "previous_archival does not point to an AppletArchival of the same Applet."
+                        .into(),
+                ))
+            }
+        }
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        applet_archival.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::ManageApplets,
+    )
+}
+pub fn validate_update_applet_archival(
+    _action: Update,
+    _applet_archival: AppletArchival,
+    _original_action: EntryCreationAction,
+    _original_applet_archival: AppletArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Applet Archivals cannot be updated",
+    )))
+}
+pub fn validate_delete_applet_archival(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_applet_archival: AppletArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Applet Archivals cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point to an AppletArchival entry
+/// 2. Link must point away from the all_applets bucket anchor of the Applet of the
+///    AppletArchival
+/// 3. The link tag must match the AppletArchival
+/// 4. The creator of the link must be the one that created the AppletArchival
+pub fn validate_create_link_all_applet_archivals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let anchor_hash = base_address
+        .into_entry_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Link base is not an entry hash".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let applet_archival: AppletArchival = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an AppletArchival entry".to_string()
+        )))?;
+
+    if all_applets_bucket_anchor(&applet_archival.applet_hash).path_entry_hash()? != anchor_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllAppletArchivals link is not pointing away from the correct anchor".into(),
+        ));
+    }
+
+    if AppletArchivalLinkTag::from_link_tag(&tag)
+        != Some(AppletArchivalLinkTag::from(&applet_archival))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not match the AppletArchival".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AppletArchival entry can create a link to it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_applet_archivals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AllAppletArchivals links cannot be deleted",
+    )))
+}
@@ -0,0 +1,139 @@
+use hdi::prelude::*;
+
+use crate::{validate_steward_permission, Applet, StewardCapability};
+
+/// Records that an Applet has been upgraded to a new happ version. The Applet entry
+/// itself stays the same so that WALs, asset relations and AppletToJoinedAgent links
+/// pointing to it remain valid.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AppletUpgrade {
+    pub permission_hash: Option<ActionHash>,
+    /// Entry hash of the Applet that gets upgraded
+    pub applet_hash: EntryHash,
+    /// ActionHash of the AppletUpgrade that this upgrade succeeds. None if this is
+    /// the first upgrade of the Applet.
+    pub previous_upgrade_hash: Option<ActionHash>,
+    pub sha256_happ: String,
+    pub sha256_ui: Option<String>,
+    pub sha256_webhapp: Option<String>,
+    pub distribution_info: String,
+    /// Whether the new version can operate on the data of the previous version
+    pub compatible: bool,
+}
+
+/// Rules
+/// 1. The applet hash must point to a valid Applet entry
+/// 2. The previous upgrade, if any, must be an upgrade of the same Applet
+/// 3. Only agents with a StewardPermission to manage applets or the progenitor can
+///    upgrade Applets
+pub fn validate_create_applet_upgrade(
+    action: EntryCreationAction,
+    applet_upgrade: AppletUpgrade,
+) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(applet_upgrade.applet_hash.clone())?;
+    if Applet::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "applet_hash does not point to an Applet entry.".into(),
+        ));
+    }
+
+    if let Some(previous_upgrade_hash) = applet_upgrade.previous_upgrade_hash.clone() {
+        let previous_upgrade: AppletUpgrade = must_get_valid_record(previous_upgrade_hash)?
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "previous_upgrade_hash does not point to an AppletUpgrade entry".to_string()
+            )))?;
+        if previous_upgrade.applet_hash != applet_upgrade.applet_hash {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The previous AppletUpgrade is for a different Applet.".into(),
+            ));
+        }
+    }
+
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        applet_upgrade.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::ManageApplets,
+    )
+}
+pub fn validate_update_applet_upgrade(
+    _action: Update,
+    _applet_upgrade: AppletUpgrade,
+    _original_action: EntryCreationAction,
+    _original_applet_upgrade: AppletUpgrade,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Applet Upgrades cannot be updated",
+    )))
+}
+pub fn validate_delete_applet_upgrade(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_applet_upgrade: AppletUpgrade,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Applet Upgrades cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point from an Applet entry hash to an AppletUpgrade entry
+/// 2. The AppletUpgrade must be an upgrade of the Applet that the link is pointing away from
+/// 3. The creator of the link must be the one that created the AppletUpgrade
+pub fn validate_create_link_applet_to_upgrades(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let applet_hash = base_address
+        .into_entry_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Link base is not an entry hash".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let applet_upgrade: AppletUpgrade = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an AppletUpgrade entry".to_string()
+        )))?;
+
+    if applet_upgrade.applet_hash != applet_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to an upgrade of a different Applet".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AppletUpgrade entry can create a link from the Applet to it"
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_applet_to_upgrades(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AppletToUpgrades links cannot be deleted",
+    )))
+}
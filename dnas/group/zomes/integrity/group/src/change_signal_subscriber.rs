@@ -0,0 +1,55 @@
+use hdi::prelude::*;
+
+/// Anchor from which all agents that opted in to receive change signals remotely get linked
+pub const CHANGE_SIGNAL_SUBSCRIBERS_ANCHOR: &str = "change_signal_subscribers";
+
+/// Rules
+/// 1. Link must point away from the change signal subscribers anchor
+/// 2. Agents can only subscribe themselves, i.e. the link must point to the public key
+///    of the creator of the link
+pub fn validate_create_link_change_signal_subscribers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    if Path::from(CHANGE_SIGNAL_SUBSCRIBERS_ANCHOR).path_entry_hash()? != base_address_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ChangeSignalSubscribers link is not pointing away from the correct anchor".into(),
+        ));
+    }
+    let subscriber =
+        target_address
+            .into_agent_pub_key()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an agent public key".to_string()
+            )))?;
+    if subscriber != action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only subscribe themselves to change signals".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the creator of the link can delete the link
+pub fn validate_delete_link_change_signal_subscribers(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a ChangeSignalSubscribers link can delete that link.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,5 +1,7 @@
 use hdi::prelude::*;
 
+use crate::{validate_steward_without_permission_hash, Applet, StewardCapability};
+
 /// Entry to register cloned cells associated to Applets
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
@@ -12,10 +14,18 @@ pub struct AppletClonedCell {
     // NOTE: It might in some cases in the future be desirable to share a membrane proof here
     // too in case it is not bound to an agent.
 }
+/// Rules
+/// 1. The applet hash must point to a valid Applet entry
 pub fn validate_create_applet_cloned_cell(
     _action: EntryCreationAction,
-    _applet_cloned_cell: AppletClonedCell,
+    applet_cloned_cell: AppletClonedCell,
 ) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(applet_cloned_cell.applet_hash)?;
+    if Applet::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "applet_hash does not point to an Applet entry.".into(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_applet_cloned_cell(
@@ -40,34 +50,68 @@ pub fn validate_delete_applet_cloned_cell(
 
 /// Rules
 /// 1. Link must point away from an Applet entry (by entry hash)
-/// 2. Link must point to an AppletClonedCell entry (by entry hash)
+/// 2. Link must point to an AppletClonedCell entry (by entry hash) of that Applet
 pub fn validate_create_link_applet_to_applet_cloned_cell(
     _action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    base_address
+    let applet_hash = base_address
         .into_entry_hash()
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Base address is not an entry hash".to_string()
         )))?;
-    target_address
-        .into_entry_hash()
-        .ok_or(wasm_error!(WasmErrorInner::Guest(
-            "Target address is not an entry hash".to_string()
-        )))?;
+    let applet_cloned_cell_hash =
+        target_address
+            .into_entry_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Target address is not an entry hash".to_string()
+            )))?;
+    let entry = must_get_entry(applet_cloned_cell_hash)?;
+    let applet_cloned_cell = match AppletClonedCell::try_from(entry.content) {
+        Ok(applet_cloned_cell) => applet_cloned_cell,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Link target is not an AppletClonedCell entry.".into(),
+            ))
+        }
+    };
+    if applet_cloned_cell.applet_hash != applet_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to an AppletClonedCell of a different Applet.".into(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 /// Rules
-/// Allowed
+/// 1. Links can only be removed by the agent that originally created the link, the
+///    group's progenitor or founders, or stewards that can manage applets. Delete link
+///    actions cannot reference a StewardPermission, so stewards need to have committed
+///    a StewardDeleteAuthorization for the link right before deleting it.
 pub fn validate_delete_link_applet_to_applet_cloned_cell(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if action.author == original_action.author {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    if let ValidateCallbackResult::Valid = validate_steward_without_permission_hash(
+        &action.author,
+        &action.prev_action,
+        &action.link_add_address,
+        &action.timestamp,
+        true,
+        StewardCapability::ManageApplets,
+    )? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the creator of an AppletToAppletClonedCell link or a steward can delete that link."
+            .into(),
+    ))
 }
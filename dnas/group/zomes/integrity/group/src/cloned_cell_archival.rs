@@ -0,0 +1,191 @@
+use hdi::prelude::*;
+
+use crate::{validate_steward_permission, AppletClonedCell, StewardCapability};
+
+/// Records that a cloned cell of an Applet has been archived or unarchived. Archived
+/// cloned cells won't be discovered by default anymore by agents that have not
+/// joined them yet.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct AppletClonedCellArchival {
+    /// ActionHash of the StewardPermission based on which the cloned cell gets (un)archived
+    pub permission_hash: Option<ActionHash>,
+    pub applet_cloned_cell_hash: EntryHash,
+    /// true if the cloned cell gets archived, false if it gets unarchived
+    pub archived: bool,
+    pub reason: Option<String>,
+    /// ActionHash of the latest AppletClonedCellArchival of the cloned cell known to the
+    /// author. AppletClonedCellArchivals are ordered by these references instead of by
+    /// their timestamps.
+    #[serde(default)]
+    pub previous_archival: Option<ActionHash>,
+}
+
+/// Tag of an AppletClonedCellToArchivals link. Carries the archival state so that the
+/// current state of a cloned cell can be read from the links alone.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, PartialEq)]
+pub struct AppletClonedCellArchivalLinkTag {
+    pub archived: bool,
+    pub previous_archival: Option<ActionHash>,
+}
+
+impl AppletClonedCellArchivalLinkTag {
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let serialized = SerializedBytes::try_from(self.clone()).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(serialized.bytes().clone()))
+    }
+
+    /// Also reads the tags of links that have been created before AppletClonedCellArchivals
+    /// referenced their previous archival, which only carry the archival state
+    pub fn from_link_tag(tag: &LinkTag) -> Option<AppletClonedCellArchivalLinkTag> {
+        let legacy_archived = match tag.as_ref() {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        };
+        if let Some(archived) = legacy_archived {
+            return Some(AppletClonedCellArchivalLinkTag {
+                archived,
+                previous_archival: None,
+            });
+        }
+        AppletClonedCellArchivalLinkTag::try_from(SerializedBytes::from(UnsafeBytes::from(
+            tag.0.clone(),
+        )))
+        .ok()
+    }
+}
+
+impl From<&AppletClonedCellArchival> for AppletClonedCellArchivalLinkTag {
+    fn from(archival: &AppletClonedCellArchival) -> Self {
+        AppletClonedCellArchivalLinkTag {
+            archived: archival.archived,
+            previous_archival: archival.previous_archival.clone(),
+        }
+    }
+}
+
+/// Rules
+/// 1. The applet cloned cell hash must point to a valid AppletClonedCell entry
+/// 2. The previous archival must point to an AppletClonedCellArchival of the same
+///    cloned cell
+/// 3. Only agents with a StewardPermission to manage applets or the progenitor can
+///    (un)archive cloned cells
+pub fn validate_create_applet_cloned_cell_archival(
+    action: EntryCreationAction,
+    archival: AppletClonedCellArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(archival.applet_cloned_cell_hash.clone())?;
+    if AppletClonedCell::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "applet_cloned_cell_hash does not point to an AppletClonedCell entry.".into(),
+        ));
+    }
+    if let Some(previous_archival_hash) = archival.previous_archival.clone() {
+        let record = must_get_valid_record(previous_archival_hash)?;
+        match AppletClonedCellArchival::try_from(record) {
+            Ok(previous_archival)
+                if previous_archival.applet_cloned_cell_hash
+                    == archival.applet_cloned_cell_hash => {}
+            _ => {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "previous_archival does not point to an AppletClonedCellArchival of the same cloned cell."
+                        .into(),
+                ))
+            }
+        }
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        archival.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::ManageApplets,
+    )
+}
+pub fn validate_update_applet_cloned_cell_archival(
+    _action: Update,
+    _archival: AppletClonedCellArchival,
+    _original_action: EntryCreationAction,
+    _original_archival: AppletClonedCellArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AppletClonedCellArchival entries cannot be updated",
+    )))
+}
+pub fn validate_delete_applet_cloned_cell_archival(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_archival: AppletClonedCellArchival,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AppletClonedCellArchival entries cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point from an AppletClonedCell entry hash to an AppletClonedCellArchival entry
+/// 2. The archival must be for the cloned cell that the link is pointing away from
+/// 3. The link tag must match the AppletClonedCellArchival
+/// 4. The creator of the link must be the one that created the AppletClonedCellArchival
+pub fn validate_create_link_applet_cloned_cell_to_archivals(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let applet_cloned_cell_hash =
+        base_address
+            .into_entry_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link base is not an entry hash".to_string()
+            )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let archival: AppletClonedCellArchival = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an AppletClonedCellArchival entry".to_string()
+        )))?;
+
+    if archival.applet_cloned_cell_hash != applet_cloned_cell_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to an archival of a different cloned cell".into(),
+        ));
+    }
+
+    if AppletClonedCellArchivalLinkTag::from_link_tag(&tag)
+        != Some(AppletClonedCellArchivalLinkTag::from(&archival))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link tag does not match the AppletClonedCellArchival".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AppletClonedCellArchival entry can create a link from the cloned cell to it"
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_applet_cloned_cell_to_archivals(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "AppletClonedCellToArchivals links cannot be deleted",
+    )))
+}
@@ -0,0 +1,75 @@
+use hdi::prelude::*;
+
+pub const ALLOWED_GROUP_ICON_MIME_TYPES: [&str; 5] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/svg+xml",
+];
+
+pub const MAX_GROUP_ICON_SIZE: usize = 300000;
+
+/// Icon of a group, stored separately from the GroupProfile so that revisions of the
+/// GroupProfile do not duplicate it. Since entries are content-addressed, identical
+/// icons are only stored once.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct GroupIcon {
+    /// base64 encoded data URL of the icon, e.g. "data:image/png;base64,..."
+    pub src: String,
+}
+
+impl GroupIcon {
+    /// The MIME type declared in the data URL
+    pub fn mime_type(&self) -> Option<&str> {
+        self.src
+            .strip_prefix("data:")
+            .and_then(|rest| rest.split_once(";base64,"))
+            .map(|(mime_type, _data)| mime_type)
+    }
+}
+
+/// Rules
+/// 1. The icon must be a base64 encoded data URL of one of the allowed MIME types
+/// 2. The icon must not be larger than 300'000 characters (approx. 200KB)
+pub fn validate_create_group_icon(
+    _action: EntryCreationAction,
+    group_icon: GroupIcon,
+) -> ExternResult<ValidateCallbackResult> {
+    match group_icon.mime_type() {
+        Some(mime_type) if ALLOWED_GROUP_ICON_MIME_TYPES.contains(&mime_type) => (),
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "The group icon must be a base64 encoded data URL of one of the following MIME types: {}",
+                ALLOWED_GROUP_ICON_MIME_TYPES.join(", ")
+            )))
+        }
+    }
+    if group_icon.src.chars().count() > MAX_GROUP_ICON_SIZE {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The group icon is not allowed to be larger than 300'000 characters (approx. 200KB)"
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_group_icon(
+    _action: Update,
+    _group_icon: GroupIcon,
+    _original_action: EntryCreationAction,
+    _original_group_icon: GroupIcon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Group Icons cannot be updated",
+    )))
+}
+pub fn validate_delete_group_icon(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_group_icon: GroupIcon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Group Icons cannot be deleted",
+    )))
+}
@@ -0,0 +1,290 @@
+use hdi::prelude::*;
+
+use crate::{
+    group_dna_properties, validate_steward_permission, validate_steward_permission_grant,
+    StewardCapability,
+};
+
+pub const ALL_GROUP_INVITATIONS_ANCHOR: &str = "all_group_invitations";
+
+/// Maximum age of the latest action of the issuer referenced by a membrane proof at
+/// the time of joining, in microseconds
+pub const MAX_MEMBRANE_PROOF_AGE: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Invitation for a specific agent to join an invite-only group, issued by the
+/// progenitor or a steward
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct GroupInvitation {
+    /// ActionHash of the StewardPermission based on which the invitation is issued
+    pub permission_hash: Option<ActionHash>,
+    pub issuer: AgentPubKey,
+    pub for_agent: AgentPubKey,
+    pub expiry: Option<Timestamp>,
+    /// Maximum number of times the invitation may be used. Joining happens at genesis
+    /// where prior uses cannot be counted deterministically, so the only use limit that
+    /// can be enforced at validation time is a limit of zero. The invitation being bound
+    /// to a single agent key already restricts it to the cells of that agent.
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+}
+
+/// The membrane proof that agents need to provide to join an invite-only group
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct GroupMembraneProof {
+    pub invitation: GroupInvitation,
+    /// Signature of the issuer over the serialized invitation
+    pub signature: Signature,
+    /// ActionHash of the GroupInvitation entry that the issuer has committed
+    pub invitation_hash: ActionHash,
+    /// Latest action of the issuer at the time they handed out the membrane proof. The
+    /// invitation must not have been deleted by the issuer up to this action.
+    pub issuer_chain_top: ActionHash,
+}
+
+/// Rules
+/// 1. The issuer of the invitation must be the author of the entry
+/// 2. Only the progenitor or agents with a StewardPermission can issue invitations
+pub fn validate_create_group_invitation(
+    action: EntryCreationAction,
+    invitation: GroupInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author() != &invitation.issuer {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The issuer of a GroupInvitation must be the author of the entry.".into(),
+        ));
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        invitation.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::InviteMembers,
+    )
+}
+pub fn validate_update_group_invitation(
+    _action: Update,
+    _invitation: GroupInvitation,
+    _original_action: EntryCreationAction,
+    _original_invitation: GroupInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Group Invitations cannot be updated",
+    )))
+}
+
+/// Rules
+/// 1. Invitations can only be deleted by their issuer or the group's progenitor or founders.
+///    This is due to a lack of the ability to pass a permission hash along with a
+///    delete action.
+pub fn validate_delete_group_invitation(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_invitation: GroupInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_issuer_or_progenitor(&action.author, original_action.author())
+}
+
+/// Rules
+/// 1. Link must point away from the all_group_invitations anchor
+/// 2. Link must point to a valid GroupInvitation entry
+/// 3. The creator of the link must be the one that created the GroupInvitation entry
+pub fn validate_create_link_all_group_invitations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    let path = Path::from(ALL_GROUP_INVITATIONS_ANCHOR);
+    if path.path_entry_hash()? != base_address_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllGroupInvitations link is not pointing away from the all_group_invitations anchor"
+                .into(),
+        ));
+    }
+
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _invitation: GroupInvitation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid("Only the creator of a GroupInvitation entry can create a link from the all_group_invitations anchor to it".into()));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Links can only be removed by the issuer of the invitation or the group's progenitor
+pub fn validate_delete_link_all_group_invitations(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_issuer_or_progenitor(&action.author, &original_action.author)
+}
+
+/// Rules
+/// 1. The membrane proof must deserialize to a GroupMembraneProof
+/// 2. The invitation must be signed by its issuer
+/// 3. The invitation must be for the joining agent
+/// 4. The invitation must not have expired at the time of joining
+/// 5. The invitation must have been committed as a valid GroupInvitation entry by its
+///    issuer. Its validation ensures that the StewardPermission of the issuer had not
+///    been revoked when the invitation was issued.
+/// 6. The issuer must have been the progenitor or a steward at the time of joining
+/// 7. The invitation must allow at least one use
+/// 8. The issuer chain top must be an action of the issuer that is at most
+///    MAX_MEMBRANE_PROOF_AGE old at the time of joining, and the issuer must not have
+///    deleted the invitation up to it
+///
+/// The issuer hands out membrane proofs with their latest action, see
+/// get_group_membrane_proof in the coordinator zome, and refuses to do so once they
+/// have deleted the invitation. Validation cannot know of actions of the issuer past
+/// the chain top, so a deleted invitation can still be used with a membrane proof that
+/// has been handed out before the delete, for at most MAX_MEMBRANE_PROOF_AGE. Deletes
+/// by anyone but the issuer only remove the invitation from the list of invitations.
+pub fn validate_group_membrane_proof(
+    agent: &AgentPubKey,
+    membrane_proof: &MembraneProof,
+    timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+    let proof = match GroupMembraneProof::try_from(membrane_proof.as_ref().clone()) {
+        Ok(proof) => proof,
+        Err(e) => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "Failed to deserialize membrane proof: {e}"
+            )));
+        }
+    };
+    let invitation = proof.invitation;
+
+    if !verify_signature(
+        invitation.issuer.clone(),
+        proof.signature,
+        invitation.clone(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invitation has not been signed by its issuer.".into(),
+        ));
+    }
+
+    if &invitation.for_agent != agent {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invitation has been issued for a different agent.".into(),
+        ));
+    }
+
+    if let Some(expiry) = invitation.expiry {
+        if &expiry < timestamp {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The invitation has expired.".into(),
+            ));
+        }
+    }
+
+    if invitation.max_uses == Some(0) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invitation does not allow any uses.".into(),
+        ));
+    }
+
+    let invitation_record = must_get_valid_record(proof.invitation_hash.clone())?;
+    let invitation_timestamp = invitation_record.action().timestamp();
+    if invitation_record.action().author() != &invitation.issuer
+        || GroupInvitation::try_from(invitation_record).ok() != Some(invitation.clone())
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invitation has not been committed by its issuer.".into(),
+        ));
+    }
+
+    let result = validate_invitation_not_deleted(
+        &invitation.issuer,
+        &proof.invitation_hash,
+        invitation_timestamp,
+        proof.issuer_chain_top,
+        timestamp,
+    )?;
+    if let ValidateCallbackResult::Invalid(_) = result {
+        return Ok(result);
+    }
+
+    validate_steward_permission_grant(
+        &invitation.issuer,
+        invitation.permission_hash,
+        timestamp,
+        true,
+        StewardCapability::InviteMembers,
+    )
+}
+
+/// Validates rule 8. of validate_group_membrane_proof
+fn validate_invitation_not_deleted(
+    issuer: &AgentPubKey,
+    invitation_hash: &ActionHash,
+    invitation_timestamp: Timestamp,
+    issuer_chain_top: ActionHash,
+    timestamp: &Timestamp,
+) -> ExternResult<ValidateCallbackResult> {
+    let chain_top = must_get_action(issuer_chain_top.clone())?;
+    if chain_top.hashed.content.author() != issuer {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The issuer chain top of the membrane proof is not an action of the issuer.".into(),
+        ));
+    }
+    if chain_top.hashed.content.timestamp().as_micros() + MAX_MEMBRANE_PROOF_AGE
+        < timestamp.as_micros()
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The membrane proof is outdated. Ask the issuer of the invitation for a new one."
+                .into(),
+        ));
+    }
+    let activity = must_get_agent_activity(
+        issuer.clone(),
+        ChainFilter::new(issuer_chain_top).until_timestamp(invitation_timestamp),
+    )?;
+    let deleted = activity.iter().any(|item| {
+        matches!(
+            &item.action.hashed.content,
+            Action::Delete(delete) if &delete.deletes_address == invitation_hash
+        )
+    });
+    if deleted {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The invitation has been deleted by its issuer.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+fn validate_issuer_or_progenitor(
+    agent: &AgentPubKey,
+    issuer: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if agent == issuer || group_dna_properties()?.is_founder(agent) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    Ok(ValidateCallbackResult::Invalid(
+        "Only the issuer of a GroupInvitation or the progenitor can delete it.".into(),
+    ))
+}
@@ -1,32 +1,183 @@
-use crate::validate_steward_permission;
+use crate::{
+    is_update_chain_revision_of, validate_steward_permission, GroupMetaDataSchema,
+    StewardCapability,
+};
 use hdi::prelude::*;
+
+/// Root component of the anchors of all GroupMetaData keys. Namespacing the anchors
+/// prevents them from colliding with other anchors in the DNA.
+pub const GROUP_META_DATA_ANCHOR_ROOT: &str = "group_meta_data";
+
+/// Anchor from which all GroupMetaData keys get linked
+pub const ALL_GROUP_META_DATA_KEYS_ANCHOR: &str = "all_group_meta_data_keys";
+
+pub const MAX_GROUP_META_DATA_NAME_LENGTH: usize = 100;
+
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct GroupMetaData {
     pub permission_hash: Option<ActionHash>,
     pub name: String,
     pub data: String,
+    /// ActionHash of the GroupMetaDataSchema that the data conforms to. Required, it
+    /// is only optional for GroupMetaData written before keys had schemas.
+    #[serde(default)]
+    pub schema_hash: Option<ActionHash>,
+    /// Set if this GroupMetaData re-publishes an earlier revision
+    #[serde(default)]
+    pub revert: Option<GroupMetaDataRevert>,
+}
+
+/// Records which earlier revision of a GroupMetaData key got re-published and why.
+/// The author of the reverting GroupMetaData entry is the one who reverted it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GroupMetaDataRevert {
+    /// ActionHash of the GroupMetaData revision that got re-published
+    pub revision_hash: ActionHash,
+    pub reason: Option<String>,
+}
+
+/// The anchor from which GroupMetaData entries and schemas of the given key get linked
+pub fn group_meta_data_anchor(name: &str) -> Path {
+    Path::from(vec![
+        Component::from(GROUP_META_DATA_ANCHOR_ROOT),
+        Component::from(name),
+    ])
+}
+
+pub fn validate_group_meta_data_name(name: &str) -> ValidateCallbackResult {
+    if name.is_empty() {
+        return ValidateCallbackResult::Invalid("GroupMetaData name must not be empty.".into());
+    }
+    if name.chars().count() > MAX_GROUP_META_DATA_NAME_LENGTH {
+        return ValidateCallbackResult::Invalid(format!(
+            "GroupMetaData name must not be longer than {MAX_GROUP_META_DATA_NAME_LENGTH} characters."
+        ));
+    }
+    ValidateCallbackResult::Valid
 }
+
+/// Rules
+/// 1. The name must be a valid GroupMetaData key name
+/// 2. A schema for the same key must be referenced and the data must conform to it
+/// 3. If an earlier revision is reverted to, the GroupMetaData must update the
+///    latest revision and have the same name and data as the reverted revision
+/// 4. Only agents with a StewardPermission to write the key or the progenitor can
+///    create GroupMetaData
 pub fn validate_create_group_meta_data(
     action: EntryCreationAction,
     group_meta_data: GroupMetaData,
 ) -> ExternResult<ValidateCallbackResult> {
+    if let ValidateCallbackResult::Invalid(reason) =
+        validate_group_meta_data_name(&group_meta_data.name)
+    {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    let result = validate_group_meta_data_schema(&group_meta_data)?;
+    if let ValidateCallbackResult::Invalid(_) = result {
+        return Ok(result);
+    }
+    if let Some(revert) = group_meta_data.revert.clone() {
+        if let EntryCreationAction::Create(_) = action {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A reverting GroupMetaData must update the latest revision of its key.".into(),
+            ));
+        }
+        let record = must_get_valid_record(revert.revision_hash)?;
+        let revision: GroupMetaData = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "revision_hash must reference a GroupMetaData entry".to_string()
+            )))?;
+        if revision.name != group_meta_data.name || revision.data != group_meta_data.data {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A reverting GroupMetaData must have the same name and data as the revision it reverts to."
+                    .into(),
+            ));
+        }
+    }
     validate_steward_permission(
         action.author(),
+        action.prev_action(),
         group_meta_data.permission_hash,
         action.timestamp(),
         true,
+        StewardCapability::WriteMetaData(group_meta_data.name.clone()),
     )
 }
+
+/// Validates that the GroupMetaData references a GroupMetaDataSchema of its key and
+/// conforms to it
+fn validate_group_meta_data_schema(
+    group_meta_data: &GroupMetaData,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(schema_hash) = group_meta_data.schema_hash.clone() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "GroupMetaData must reference the GroupMetaDataSchema of its key.".into(),
+        ));
+    };
+    let record = must_get_valid_record(schema_hash)?;
+    let schema: GroupMetaDataSchema = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "schema_hash must reference a GroupMetaDataSchema entry".to_string()
+        )))?;
+    if schema.name != group_meta_data.name {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The referenced GroupMetaDataSchema is for a different key.".into(),
+        ));
+    }
+    if !schema.value_type.accepts(&group_meta_data.data) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "GroupMetaData does not conform to the schema of key '{}'.",
+            group_meta_data.name
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. The name must not change
+/// 2. The referenced schema must be the schema referenced by the updated revision or
+///    result from updates of it, i.e. the schema of a key cannot be rolled back by
+///    writing GroupMetaData
+/// 3. If an earlier revision is reverted to, it must be the updated revision or one
+///    that it results from, so that the revert supersedes all revisions after it in
+///    the update chain
+/// 4. All rules of validate_create_group_meta_data apply
 pub fn validate_update_group_meta_data(
-    _action: Update,
-    _group_meta_data: GroupMetaData,
+    action: Update,
+    group_meta_data: GroupMetaData,
     _original_action: EntryCreationAction,
-    _original_group_meta_data: GroupMetaData,
+    original_group_meta_data: GroupMetaData,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from(
-        "GroupMetaData entries cannot be updated",
-    )))
+    if group_meta_data.name != original_group_meta_data.name {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The name of a GroupMetaData entry cannot be changed.".into(),
+        ));
+    }
+    if let (Some(schema_hash), Some(original_schema_hash)) = (
+        group_meta_data.schema_hash.as_ref(),
+        original_group_meta_data.schema_hash.as_ref(),
+    ) {
+        if !is_update_chain_revision_of(schema_hash, original_schema_hash)? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "GroupMetaData must reference the current GroupMetaDataSchema of its key.".into(),
+            ));
+        }
+    }
+    if let Some(revert) = group_meta_data.revert.as_ref() {
+        if !is_update_chain_revision_of(&action.original_action_address, &revert.revision_hash)? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A reverting GroupMetaData must revert to an earlier revision of the GroupMetaData it updates.".into(),
+            ));
+        }
+    }
+    validate_create_group_meta_data(EntryCreationAction::Update(action), group_meta_data)
 }
 pub fn validate_delete_group_meta_data(
     _action: Delete,
@@ -70,7 +221,7 @@ pub fn validate_create_link_group_meta_data_to_anchor(
             "Base address is not an entry hash".into()
         ))
     })?;
-    let path = Path::from(group_meta_data.name.as_str());
+    let path = group_meta_data_anchor(&group_meta_data.name);
     if path.path_entry_hash()? != base_address_entry_hash {
         return Ok(ValidateCallbackResult::Invalid(
             "GroupMetaDataToAnchor link is not pointing away from the correctly named anchor"
@@ -94,3 +245,57 @@ pub fn validate_delete_link_group_meta_data_to_anchor(
         "Links to group meta data entries cannot be deleted.".into(),
     ))
 }
+
+/// Rules
+/// 1. Link must point away from the anchor of all GroupMetaData keys
+/// 2. Link must point to the anchor of the key named in the link tag
+pub fn validate_create_link_all_group_meta_data_keys(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    if Path::from(ALL_GROUP_META_DATA_KEYS_ANCHOR).path_entry_hash()? != base_address_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllGroupMetaDataKeys link is not pointing away from the correct anchor".into(),
+        ));
+    }
+    let name = match String::from_utf8(tag.into_inner()) {
+        Ok(name) => name,
+        Err(_) => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "AllGroupMetaDataKeys link tag is not a valid key name".into(),
+            ))
+        }
+    };
+    if let ValidateCallbackResult::Invalid(reason) = validate_group_meta_data_name(&name) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    let target_entry_hash = EntryHash::try_from(target_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Target address is not an entry hash".into()
+        ))
+    })?;
+    if group_meta_data_anchor(&name).path_entry_hash()? != target_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllGroupMetaDataKeys link is not pointing to the anchor of the key in its tag".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_group_meta_data_keys(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "AllGroupMetaDataKeys links cannot be deleted.".into(),
+    ))
+}
@@ -0,0 +1,168 @@
+use hdi::prelude::*;
+
+use crate::{
+    group_meta_data_anchor, validate_group_meta_data_name, validate_steward_permission,
+    StewardCapability,
+};
+
+/// The type of values that a GroupMetaData key accepts
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "content")]
+pub enum GroupMetaDataValueType {
+    /// Any string
+    String,
+    /// A JSON number
+    Number,
+    /// A JSON boolean, i.e. "true" or "false"
+    Boolean,
+    /// Any valid JSON
+    Json,
+    /// A JSON object
+    JsonObject,
+    /// A JSON array
+    JsonArray,
+    /// One of the listed strings
+    Enum(Vec<String>),
+    /// Any of the listed types
+    OneOf(Vec<GroupMetaDataValueType>),
+}
+
+impl GroupMetaDataValueType {
+    /// Checks whether the given data conforms to this type
+    pub fn accepts(&self, data: &str) -> bool {
+        let json = || serde_json::from_str::<serde_json::Value>(data).ok();
+        match self {
+            GroupMetaDataValueType::String => true,
+            GroupMetaDataValueType::Number => json().is_some_and(|v| v.is_number()),
+            GroupMetaDataValueType::Boolean => json().is_some_and(|v| v.is_boolean()),
+            GroupMetaDataValueType::Json => json().is_some(),
+            GroupMetaDataValueType::JsonObject => json().is_some_and(|v| v.is_object()),
+            GroupMetaDataValueType::JsonArray => json().is_some_and(|v| v.is_array()),
+            GroupMetaDataValueType::Enum(values) => values.iter().any(|v| v == data),
+            GroupMetaDataValueType::OneOf(types) => types.iter().any(|t| t.accepts(data)),
+        }
+    }
+}
+
+/// Registry entry declaring the type of values that a GroupMetaData key accepts.
+/// GroupMetaData entries are validated against the schema they reference. Schemas of
+/// a key are changed by updating the latest GroupMetaDataSchema of the key.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct GroupMetaDataSchema {
+    pub permission_hash: Option<ActionHash>,
+    /// Name of the GroupMetaData key that this schema applies to
+    pub name: String,
+    pub value_type: GroupMetaDataValueType,
+    pub description: Option<String>,
+}
+
+/// Rules
+/// 1. The name must be a valid GroupMetaData key name
+/// 2. Only agents with a StewardPermission to write the key or the progenitor can
+///    register a schema for it
+pub fn validate_create_group_meta_data_schema(
+    action: EntryCreationAction,
+    schema: GroupMetaDataSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    if let ValidateCallbackResult::Invalid(reason) = validate_group_meta_data_name(&schema.name) {
+        return Ok(ValidateCallbackResult::Invalid(reason));
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        schema.permission_hash,
+        action.timestamp(),
+        true,
+        StewardCapability::WriteMetaData(schema.name.clone()),
+    )
+}
+/// Rules
+/// 1. The name must not change
+/// 2. Only agents with a StewardPermission to write the key or the progenitor can
+///    update its schema
+pub fn validate_update_group_meta_data_schema(
+    action: Update,
+    schema: GroupMetaDataSchema,
+    _original_action: EntryCreationAction,
+    original_schema: GroupMetaDataSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    if schema.name != original_schema.name {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The name of a GroupMetaDataSchema cannot be changed.".into(),
+        ));
+    }
+    validate_steward_permission(
+        &action.author,
+        &action.prev_action,
+        schema.permission_hash,
+        &action.timestamp,
+        true,
+        StewardCapability::WriteMetaData(schema.name.clone()),
+    )
+}
+
+pub fn validate_delete_group_meta_data_schema(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_schema: GroupMetaDataSchema,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "GroupMetaDataSchema entries cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point away from the anchor of the key that the schema applies to
+/// 2. Link must point to a valid GroupMetaDataSchema entry
+/// 3. The creator of the link must be the one that created the GroupMetaDataSchema entry
+pub fn validate_create_link_group_meta_data_key_to_schemas(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let schema: GroupMetaDataSchema = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a GroupMetaDataSchema entry".to_string()
+        )))?;
+
+    let base_address_entry_hash = EntryHash::try_from(base_address).map_err(|_| {
+        wasm_error!(WasmErrorInner::Guest(
+            "Base address is not an entry hash".into()
+        ))
+    })?;
+    if group_meta_data_anchor(&schema.name).path_entry_hash()? != base_address_entry_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "GroupMetaDataKeyToSchemas link is not pointing away from the anchor of the key".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a GroupMetaDataSchema entry can create a link to it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_group_meta_data_key_to_schemas(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "GroupMetaDataKeyToSchemas links cannot be deleted.".into(),
+    ))
+}
@@ -1,13 +1,25 @@
-use crate::validate_steward_permission;
+use crate::{validate_steward_permission, GroupIcon, StewardCapability};
 use hdi::prelude::*;
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
 pub struct GroupProfile {
     pub permission_hash: Option<ActionHash>,
     pub name: String,
+    /// Inline icon as data URL. Deprecated in favor of icon_hash and must be empty
+    /// if icon_hash is set.
+    #[serde(default)]
     pub icon_src: String,
     pub meta_data: Option<String>,
+    /// Entry hash of the GroupIcon of the group
+    #[serde(default)]
+    pub icon_hash: Option<EntryHash>,
 }
+/// Rules
+/// 1. An inline icon must not be larger than 300'000 characters (approx. 200KB)
+/// 2. The icon hash, if any, must point to a valid GroupIcon entry
+/// 3. A GroupProfile cannot have both an inline icon and an icon hash
+/// 4. Only agents with a StewardPermission to edit the profile or the progenitor can
+///    create a GroupProfile
 pub fn validate_create_group_profile(
     action: EntryCreationAction,
     group_profile: GroupProfile,
@@ -18,22 +30,38 @@ pub fn validate_create_group_profile(
                 .into(),
         ));
     }
+    if let Some(icon_hash) = group_profile.icon_hash.clone() {
+        let entry = must_get_entry(icon_hash)?;
+        if GroupIcon::try_from(entry.content).is_err() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "icon_hash does not point to a GroupIcon entry.".into(),
+            ));
+        }
+        if !group_profile.icon_src.is_empty() {
+            return Ok(ValidateCallbackResult::Invalid(
+                "A GroupProfile with an icon_hash cannot have an inline icon_src.".into(),
+            ));
+        }
+    }
     validate_steward_permission(
         action.author(),
+        action.prev_action(),
         group_profile.permission_hash,
         action.timestamp(),
         true,
+        StewardCapability::EditProfile,
     )
 }
+/// Rules
+/// 1. Only agents with a StewardPermission to edit the profile or the progenitor can
+///    update the GroupProfile. This is the same rule as for creating a GroupProfile.
 pub fn validate_update_group_profile(
-    _action: Update,
-    _group_profile: GroupProfile,
+    action: Update,
+    group_profile: GroupProfile,
     _original_action: EntryCreationAction,
     _original_group_profile: GroupProfile,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from(
-        "Group Profiles cannot be updated",
-    )))
+    validate_create_group_profile(EntryCreationAction::Update(action), group_profile)
 }
 pub fn validate_delete_group_profile(
     _action: Delete,
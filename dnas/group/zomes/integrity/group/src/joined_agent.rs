@@ -1,20 +1,50 @@
 use hdi::prelude::*;
 
+use crate::Applet;
+
+/// Tag of an AppletToJoinedAgent link. Binds the public key that the agent uses in
+/// the applet to their public key in the group.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct JoinedAgentLinkTag {
+    pub applet_pubkey: AgentPubKey,
+    /// Signature of the applet public key over the group public key of the agent
+    pub signature: Signature,
+}
+
+impl JoinedAgentLinkTag {
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let serialized = SerializedBytes::try_from(self.clone()).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(serialized.bytes().clone()))
+    }
+
+    pub fn from_link_tag(tag: &LinkTag) -> Option<JoinedAgentLinkTag> {
+        JoinedAgentLinkTag::try_from(SerializedBytes::from(UnsafeBytes::from(tag.0.clone()))).ok()
+    }
+}
+
 /// Rules
-/// 1. Link base must be an entry hash
+/// 1. Link base must be the entry hash of a valid Applet entry
 /// 2. Link target must be the agent public key of the link creator
+/// 3. Link tag must contain the public key that the agent uses in the applet
+/// 4. Link tag must contain a signature of the applet public key over the public
+///    key of the link creator
 pub fn validate_create_link_joined_agent(
     action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check the entry type for the given action hash
-    let _entry_hash = base_address
+    let entry_hash = base_address
         .into_entry_hash()
         .ok_or(wasm_error!(WasmErrorInner::Guest(
-            "No action hash associated with link".to_string()
+            "Link base is not an entry hash".to_string()
         )))?;
+    let entry = must_get_entry(entry_hash)?;
+    if Applet::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AppletToJoinedAgent links must point away from an Applet entry.".into(),
+        ));
+    }
 
     let agent = match target_address
         .into_agent_pub_key()
@@ -31,6 +61,25 @@ pub fn validate_create_link_joined_agent(
         ));
     }
 
+    let joined_agent_tag = match JoinedAgentLinkTag::from_link_tag(&tag) {
+        Some(t) => t,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Link tag is not a valid JoinedAgentLinkTag.".into(),
+            ))
+        }
+    };
+
+    if !verify_signature(
+        joined_agent_tag.applet_pubkey,
+        joined_agent_tag.signature,
+        agent,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The applet public key has not signed the public key of the link creator.".into(),
+        ));
+    }
+
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -1,15 +1,29 @@
 pub mod group_profile;
 pub use group_profile::*;
+pub mod group_icon;
+pub use group_icon::*;
 pub mod group_meta_data;
 pub use group_meta_data::*;
+pub mod group_meta_data_schema;
+pub use group_meta_data_schema::*;
 pub mod applet;
 pub use applet::*;
+pub mod applet_archival;
+pub use applet_archival::*;
+pub mod applet_upgrade;
+pub use applet_upgrade::*;
 pub mod applet_private;
 pub use applet_private::*;
 pub mod cloned_cell;
 pub use cloned_cell::*;
+pub mod cloned_cell_archival;
+pub use cloned_cell_archival::*;
 pub mod cloned_cell_private;
 pub use cloned_cell_private::*;
+pub mod change_signal_subscriber;
+pub use change_signal_subscriber::*;
+pub mod shared_cloned_cell;
+pub use shared_cloned_cell::*;
 pub mod joined_agent;
 pub use joined_agent::*;
 pub mod abandoned_agent;
@@ -17,12 +31,35 @@ pub use abandoned_agent::*;
 pub mod steward_permission;
 pub use steward_permission::*;
 pub mod steward_permission_claim;
-use hdi::prelude::*;
 pub use steward_permission_claim::*;
+pub mod steward_permission_revocation;
+pub use steward_permission_revocation::*;
+pub mod group_invitation;
+pub use group_invitation::*;
+pub mod steward_permission_revocation_receipt;
+use hdi::prelude::*;
+pub use steward_permission_revocation_receipt::*;
+pub mod steward_delete_authorization;
+pub use steward_delete_authorization::*;
+pub use steward_permissions::*;
 
-#[derive(Clone, Serialize, Deserialize, Debug, SerializedBytes)]
-pub struct GroupDnaProperties {
-    pub progenitor: Option<AgentPubKeyB64>,
+/// Whether the action with the given hash is the given earlier action or results from
+/// updates of it, following the original action addresses of the updates
+pub fn is_update_chain_revision_of(
+    action_hash: &ActionHash,
+    earlier_action_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let mut next_hash = Some(action_hash.clone());
+    while let Some(action_hash) = next_hash {
+        if &action_hash == earlier_action_hash {
+            return Ok(true);
+        }
+        next_hash = match must_get_action(action_hash)?.hashed.content {
+            Action::Update(update) => Some(update.original_action_address),
+            _ => None,
+        };
+    }
+    Ok(false)
 }
 
 #[derive(Serialize, Deserialize)]
@@ -41,7 +78,31 @@ pub enum EntryTypes {
     AppletClonedCellPrivate(AppletClonedCellPrivate),
     GroupProfile(GroupProfile),
     GroupMetaData(GroupMetaData),
+    StewardPermissionRevocation(StewardPermissionRevocation),
+    GroupInvitation(GroupInvitation),
+    AppletUpgrade(AppletUpgrade),
+    AppletArchival(AppletArchival),
+    AppletClonedCellArchival(AppletClonedCellArchival),
+    SharedAppletClonedCell(SharedAppletClonedCell),
+    GroupIcon(GroupIcon),
+    GroupMetaDataSchema(GroupMetaDataSchema),
+    StewardPermissionRevocationReceipt(StewardPermissionRevocationReceipt),
+    StewardDeleteAuthorization(StewardDeleteAuthorization),
 }
+
+// The steward_permissions crate identifies these entries by their position in
+// EntryTypes, so that other integrity zomes of the group DNA can read them
+const _: () = {
+    assert!(UnitEntryTypes::StewardPermission as u8 == STEWARD_PERMISSION_ENTRY_INDEX);
+    assert!(
+        UnitEntryTypes::StewardPermissionRevocationReceipt as u8
+            == STEWARD_PERMISSION_REVOCATION_RECEIPT_ENTRY_INDEX
+    );
+    assert!(
+        UnitEntryTypes::StewardDeleteAuthorization as u8
+            == STEWARD_DELETE_AUTHORIZATION_ENTRY_INDEX
+    );
+};
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
 pub enum LinkTypes {
@@ -53,16 +114,68 @@ pub enum LinkTypes {
     AppletToJoinedAgent,
     AppletToAbandonedAgent,
     GroupMetaDataToAnchor,
+    StewardPermissionToRevocations,
+    AllGroupInvitations,
+    AppletToUpgrades,
+    AllAppletArchivals,
+    AppletClonedCellToArchivals,
+    AgentToSharedAppletClonedCells,
+    GroupMetaDataKeyToSchemas,
+    AllGroupMetaDataKeys,
+    ChangeSignalSubscribers,
 }
 #[hdk_extern]
-pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+pub fn genesis_self_check(data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
+    let dna_properties = group_dna_properties()?;
+    if !requires_membrane_proof(&dna_properties, &data.agent_key) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match data.membrane_proof {
+        None => Ok(ValidateCallbackResult::Invalid(
+            "This group is invite-only and no membrane proof has been provided.".into(),
+        )),
+        Some(membrane_proof) => {
+            match GroupMembraneProof::try_from(membrane_proof.as_ref().clone()) {
+                Ok(proof) => {
+                    if proof.invitation.for_agent != data.agent_key {
+                        return Ok(ValidateCallbackResult::Invalid(
+                            "The invitation has been issued for a different agent.".into(),
+                        ));
+                    }
+                    Ok(ValidateCallbackResult::Valid)
+                }
+                Err(e) => Ok(ValidateCallbackResult::Invalid(format!(
+                    "Failed to deserialize membrane proof: {e}"
+                ))),
+            }
+        }
+    }
 }
+/// Rules
+/// 1. Agents can join freely unless the group is invite-only
+/// 2. In invite-only groups, the progenitor and founders can join without membrane proof
+/// 3. All other agents need to provide a valid GroupMembraneProof
 pub fn validate_agent_joining(
-    _agent_pub_key: AgentPubKey,
-    _membrane_proof: &Option<MembraneProof>,
+    agent_pub_key: AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
+    timestamp: &Timestamp,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    let dna_properties = group_dna_properties()?;
+    if !requires_membrane_proof(&dna_properties, &agent_pub_key) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match membrane_proof {
+        Some(membrane_proof) => {
+            validate_group_membrane_proof(&agent_pub_key, membrane_proof, timestamp)
+        }
+        None => Ok(ValidateCallbackResult::Invalid(
+            "This group is invite-only and no membrane proof has been provided.".into(),
+        )),
+    }
+}
+fn requires_membrane_proof(dna_properties: &GroupDnaProperties, agent: &AgentPubKey) -> bool {
+    // Without a progenitor or founders there is nobody to issue the first invitation
+    dna_properties.invite_only && !dna_properties.is_founder(agent)
 }
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
@@ -108,6 +221,55 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         claim,
                     )
                 }
+                EntryTypes::StewardPermissionRevocation(revocation) => {
+                    validate_create_steward_permission_revocation(
+                        EntryCreationAction::Create(action),
+                        revocation,
+                    )
+                }
+                EntryTypes::GroupInvitation(invitation) => validate_create_group_invitation(
+                    EntryCreationAction::Create(action),
+                    invitation,
+                ),
+                EntryTypes::AppletUpgrade(applet_upgrade) => validate_create_applet_upgrade(
+                    EntryCreationAction::Create(action),
+                    applet_upgrade,
+                ),
+                EntryTypes::AppletArchival(applet_archival) => validate_create_applet_archival(
+                    EntryCreationAction::Create(action),
+                    applet_archival,
+                ),
+                EntryTypes::AppletClonedCellArchival(archival) => {
+                    validate_create_applet_cloned_cell_archival(
+                        EntryCreationAction::Create(action),
+                        archival,
+                    )
+                }
+                EntryTypes::SharedAppletClonedCell(shared_cloned_cell) => {
+                    validate_create_shared_applet_cloned_cell(
+                        EntryCreationAction::Create(action),
+                        shared_cloned_cell,
+                    )
+                }
+                EntryTypes::GroupIcon(group_icon) => {
+                    validate_create_group_icon(EntryCreationAction::Create(action), group_icon)
+                }
+                EntryTypes::GroupMetaDataSchema(schema) => validate_create_group_meta_data_schema(
+                    EntryCreationAction::Create(action),
+                    schema,
+                ),
+                EntryTypes::StewardPermissionRevocationReceipt(receipt) => {
+                    validate_create_steward_permission_revocation_receipt(
+                        EntryCreationAction::Create(action),
+                        receipt,
+                    )
+                }
+                EntryTypes::StewardDeleteAuthorization(authorization) => {
+                    validate_create_steward_delete_authorization(
+                        EntryCreationAction::Create(action),
+                        authorization,
+                    )
+                }
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -148,6 +310,55 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         claim,
                     )
                 }
+                EntryTypes::StewardPermissionRevocation(revocation) => {
+                    validate_create_steward_permission_revocation(
+                        EntryCreationAction::Update(action),
+                        revocation,
+                    )
+                }
+                EntryTypes::GroupInvitation(invitation) => validate_create_group_invitation(
+                    EntryCreationAction::Update(action),
+                    invitation,
+                ),
+                EntryTypes::AppletUpgrade(applet_upgrade) => validate_create_applet_upgrade(
+                    EntryCreationAction::Update(action),
+                    applet_upgrade,
+                ),
+                EntryTypes::AppletArchival(applet_archival) => validate_create_applet_archival(
+                    EntryCreationAction::Update(action),
+                    applet_archival,
+                ),
+                EntryTypes::AppletClonedCellArchival(archival) => {
+                    validate_create_applet_cloned_cell_archival(
+                        EntryCreationAction::Update(action),
+                        archival,
+                    )
+                }
+                EntryTypes::SharedAppletClonedCell(shared_cloned_cell) => {
+                    validate_create_shared_applet_cloned_cell(
+                        EntryCreationAction::Update(action),
+                        shared_cloned_cell,
+                    )
+                }
+                EntryTypes::GroupIcon(group_icon) => {
+                    validate_create_group_icon(EntryCreationAction::Update(action), group_icon)
+                }
+                EntryTypes::GroupMetaDataSchema(schema) => validate_create_group_meta_data_schema(
+                    EntryCreationAction::Update(action),
+                    schema,
+                ),
+                EntryTypes::StewardPermissionRevocationReceipt(receipt) => {
+                    validate_create_steward_permission_revocation_receipt(
+                        EntryCreationAction::Update(action),
+                        receipt,
+                    )
+                }
+                EntryTypes::StewardDeleteAuthorization(authorization) => {
+                    validate_create_steward_delete_authorization(
+                        EntryCreationAction::Update(action),
+                        authorization,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -268,6 +479,197 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryTypes::StewardPermissionClaim(_) => Ok(ValidateCallbackResult::Invalid(
                         "A private steward permission claim entry cannot be updated".into(),
                     )),
+                    EntryTypes::StewardPermissionRevocation(revocation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_revocation = match StewardPermissionRevocation::try_from(
+                            original_app_entry,
+                        ) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get StewardPermissionRevocation from Record: {e:?}"
+                                    )));
+                            }
+                        };
+                        validate_update_steward_permission_revocation(
+                            action,
+                            revocation,
+                            original_create_action,
+                            original_revocation,
+                        )
+                    }
+                    EntryTypes::GroupInvitation(invitation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_invitation =
+                            match GroupInvitation::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get GroupInvitation from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_group_invitation(
+                            action,
+                            invitation,
+                            original_create_action,
+                            original_invitation,
+                        )
+                    }
+                    EntryTypes::AppletUpgrade(applet_upgrade) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_applet_upgrade =
+                            match AppletUpgrade::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AppletUpgrade from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_applet_upgrade(
+                            action,
+                            applet_upgrade,
+                            original_create_action,
+                            original_applet_upgrade,
+                        )
+                    }
+                    EntryTypes::AppletArchival(applet_archival) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_applet_archival =
+                            match AppletArchival::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get AppletArchival from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_applet_archival(
+                            action,
+                            applet_archival,
+                            original_create_action,
+                            original_applet_archival,
+                        )
+                    }
+                    EntryTypes::AppletClonedCellArchival(archival) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_archival =
+                            match AppletClonedCellArchival::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get AppletClonedCellArchival from Record: {e:?}"
+                                )));
+                                }
+                            };
+                        validate_update_applet_cloned_cell_archival(
+                            action,
+                            archival,
+                            original_create_action,
+                            original_archival,
+                        )
+                    }
+                    EntryTypes::SharedAppletClonedCell(shared_cloned_cell) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_shared_cloned_cell =
+                            match SharedAppletClonedCell::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get SharedAppletClonedCell from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_shared_applet_cloned_cell(
+                            action,
+                            shared_cloned_cell,
+                            original_create_action,
+                            original_shared_cloned_cell,
+                        )
+                    }
+                    EntryTypes::GroupIcon(group_icon) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_group_icon = match GroupIcon::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get GroupIcon from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_group_icon(
+                            action,
+                            group_icon,
+                            original_create_action,
+                            original_group_icon,
+                        )
+                    }
+                    EntryTypes::GroupMetaDataSchema(schema) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_schema =
+                            match GroupMetaDataSchema::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get GroupMetaDataSchema from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_group_meta_data_schema(
+                            action,
+                            schema,
+                            original_create_action,
+                            original_schema,
+                        )
+                    }
+                    EntryTypes::StewardPermissionRevocationReceipt(receipt) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_receipt = match StewardPermissionRevocationReceipt::try_from(
+                            original_app_entry,
+                        ) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get StewardPermissionRevocationReceipt from Record: {e:?}"
+                                    )));
+                            }
+                        };
+                        validate_update_steward_permission_revocation_receipt(
+                            action,
+                            receipt,
+                            original_create_action,
+                            original_receipt,
+                        )
+                    }
+                    EntryTypes::StewardDeleteAuthorization(authorization) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_authorization =
+                            match StewardDeleteAuthorization::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get StewardDeleteAuthorization from Record: {e:?}"
+                                )));
+                                }
+                            };
+                        validate_update_steward_delete_authorization(
+                            action,
+                            authorization,
+                            original_create_action,
+                            original_authorization,
+                        )
+                    }
                 }
             }
             _ => Ok(ValidateCallbackResult::Valid),
@@ -338,6 +740,74 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         original_steward_permission,
                     )
                 }
+                EntryTypes::StewardPermissionRevocation(original_revocation) => {
+                    validate_delete_steward_permission_revocation(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_revocation,
+                    )
+                }
+                EntryTypes::GroupInvitation(original_invitation) => {
+                    validate_delete_group_invitation(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_invitation,
+                    )
+                }
+                EntryTypes::AppletUpgrade(original_applet_upgrade) => {
+                    validate_delete_applet_upgrade(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_applet_upgrade,
+                    )
+                }
+                EntryTypes::AppletArchival(original_applet_archival) => {
+                    validate_delete_applet_archival(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_applet_archival,
+                    )
+                }
+                EntryTypes::AppletClonedCellArchival(original_archival) => {
+                    validate_delete_applet_cloned_cell_archival(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_archival,
+                    )
+                }
+                EntryTypes::SharedAppletClonedCell(original_shared_cloned_cell) => {
+                    validate_delete_shared_applet_cloned_cell(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_shared_cloned_cell,
+                    )
+                }
+                EntryTypes::GroupIcon(original_group_icon) => validate_delete_group_icon(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_group_icon,
+                ),
+                EntryTypes::GroupMetaDataSchema(original_schema) => {
+                    validate_delete_group_meta_data_schema(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_schema,
+                    )
+                }
+                EntryTypes::StewardPermissionRevocationReceipt(original_receipt) => {
+                    validate_delete_steward_permission_revocation_receipt(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_receipt,
+                    )
+                }
+                EntryTypes::StewardDeleteAuthorization(original_authorization) => {
+                    validate_delete_steward_delete_authorization(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_authorization,
+                    )
+                }
                 // Note that a private entry should never show up down here in the first place
                 _ => Ok(ValidateCallbackResult::Invalid(
                     "AppletPrivate match arm should never get called in the first place".into(),
@@ -391,53 +861,109 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
-        },
-        FlatOp::RegisterDeleteLink {
-            link_type,
-            base_address,
-            target_address,
-            tag,
-            original_action,
-            action,
-        } => match link_type {
-            LinkTypes::AgentToStewardPermissions => {
-                validate_delete_link_agent_to_steward_permissions(
+            LinkTypes::StewardPermissionToRevocations => {
+                validate_create_link_steward_permission_to_revocations(
                     action,
-                    original_action,
                     base_address,
                     target_address,
                     tag,
                 )
             }
-            LinkTypes::AllStewardPermissions => validate_delete_link_all_steward_permissions(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
-            ),
-            LinkTypes::AllApplets => validate_delete_link_all_applets(
+            LinkTypes::AllGroupInvitations => validate_create_link_all_group_invitations(
                 action,
-                original_action,
                 base_address,
                 target_address,
                 tag,
             ),
-            LinkTypes::AppletToAppletClonedCell => {
-                validate_delete_link_applet_to_applet_cloned_cell(
+            LinkTypes::AppletToUpgrades => {
+                validate_create_link_applet_to_upgrades(action, base_address, target_address, tag)
+            }
+            LinkTypes::AllAppletArchivals => {
+                validate_create_link_all_applet_archivals(action, base_address, target_address, tag)
+            }
+            LinkTypes::AppletClonedCellToArchivals => {
+                validate_create_link_applet_cloned_cell_to_archivals(
                     action,
-                    original_action,
                     base_address,
                     target_address,
                     tag,
                 )
             }
-            LinkTypes::AllGroupProfiles => validate_delete_link_all_group_profiles(
-                action,
-                original_action,
-                base_address,
-                target_address,
-                tag,
+            LinkTypes::AgentToSharedAppletClonedCells => {
+                validate_create_link_agent_to_shared_applet_cloned_cells(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::GroupMetaDataKeyToSchemas => {
+                validate_create_link_group_meta_data_key_to_schemas(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllGroupMetaDataKeys => validate_create_link_all_group_meta_data_keys(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ChangeSignalSubscribers => validate_create_link_change_signal_subscribers(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+        },
+        FlatOp::RegisterDeleteLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            original_action,
+            action,
+        } => match link_type {
+            LinkTypes::AgentToStewardPermissions => {
+                validate_delete_link_agent_to_steward_permissions(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllStewardPermissions => validate_delete_link_all_steward_permissions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllApplets => validate_delete_link_all_applets(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AppletToAppletClonedCell => {
+                validate_delete_link_applet_to_applet_cloned_cell(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllGroupProfiles => validate_delete_link_all_group_profiles(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
             ),
             LinkTypes::AppletToJoinedAgent => validate_delete_link_joined_agent(
                 action,
@@ -460,6 +986,77 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
+            LinkTypes::StewardPermissionToRevocations => {
+                validate_delete_link_steward_permission_to_revocations(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllGroupInvitations => validate_delete_link_all_group_invitations(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AppletToUpgrades => validate_delete_link_applet_to_upgrades(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AllAppletArchivals => validate_delete_link_all_applet_archivals(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AppletClonedCellToArchivals => {
+                validate_delete_link_applet_cloned_cell_to_archivals(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AgentToSharedAppletClonedCells => {
+                validate_delete_link_agent_to_shared_applet_cloned_cells(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::GroupMetaDataKeyToSchemas => {
+                validate_delete_link_group_meta_data_key_to_schemas(
+                    action,
+                    original_action,
+                    base_address,
+                    target_address,
+                    tag,
+                )
+            }
+            LinkTypes::AllGroupMetaDataKeys => validate_delete_link_all_group_meta_data_keys(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ChangeSignalSubscribers => validate_delete_link_change_signal_subscribers(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
         },
         FlatOp::StoreRecord(store_record) => match store_record {
             OpRecord::CreateEntry { app_entry, action } => match app_entry {
@@ -499,6 +1096,55 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         claim,
                     )
                 }
+                EntryTypes::StewardPermissionRevocation(revocation) => {
+                    validate_create_steward_permission_revocation(
+                        EntryCreationAction::Create(action),
+                        revocation,
+                    )
+                }
+                EntryTypes::GroupInvitation(invitation) => validate_create_group_invitation(
+                    EntryCreationAction::Create(action),
+                    invitation,
+                ),
+                EntryTypes::AppletUpgrade(applet_upgrade) => validate_create_applet_upgrade(
+                    EntryCreationAction::Create(action),
+                    applet_upgrade,
+                ),
+                EntryTypes::AppletArchival(applet_archival) => validate_create_applet_archival(
+                    EntryCreationAction::Create(action),
+                    applet_archival,
+                ),
+                EntryTypes::AppletClonedCellArchival(archival) => {
+                    validate_create_applet_cloned_cell_archival(
+                        EntryCreationAction::Create(action),
+                        archival,
+                    )
+                }
+                EntryTypes::SharedAppletClonedCell(shared_cloned_cell) => {
+                    validate_create_shared_applet_cloned_cell(
+                        EntryCreationAction::Create(action),
+                        shared_cloned_cell,
+                    )
+                }
+                EntryTypes::GroupIcon(group_icon) => {
+                    validate_create_group_icon(EntryCreationAction::Create(action), group_icon)
+                }
+                EntryTypes::GroupMetaDataSchema(schema) => validate_create_group_meta_data_schema(
+                    EntryCreationAction::Create(action),
+                    schema,
+                ),
+                EntryTypes::StewardPermissionRevocationReceipt(receipt) => {
+                    validate_create_steward_permission_revocation_receipt(
+                        EntryCreationAction::Create(action),
+                        receipt,
+                    )
+                }
+                EntryTypes::StewardDeleteAuthorization(authorization) => {
+                    validate_create_steward_delete_authorization(
+                        EntryCreationAction::Create(action),
+                        authorization,
+                    )
+                }
             },
             OpRecord::UpdateEntry {
                 original_action_hash,
@@ -680,6 +1326,267 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     EntryTypes::StewardPermissionClaim(_) => Ok(ValidateCallbackResult::Invalid(
                         "StewardPermissionClaim entry cannot be updated.".into(),
                     )),
+                    EntryTypes::StewardPermissionRevocation(revocation) => {
+                        let result = validate_create_steward_permission_revocation(
+                            EntryCreationAction::Update(action.clone()),
+                            revocation.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_revocation: Option<StewardPermissionRevocation> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_revocation = match original_revocation {
+                                Some(revocation) => revocation,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_steward_permission_revocation(
+                                action,
+                                revocation,
+                                original_action,
+                                original_revocation,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::GroupInvitation(invitation) => {
+                        let result = validate_create_group_invitation(
+                            EntryCreationAction::Update(action.clone()),
+                            invitation.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_invitation: Option<GroupInvitation> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_invitation = match original_invitation {
+                                Some(invitation) => invitation,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_group_invitation(
+                                action,
+                                invitation,
+                                original_action,
+                                original_invitation,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AppletUpgrade(applet_upgrade) => {
+                        let result = validate_create_applet_upgrade(
+                            EntryCreationAction::Update(action.clone()),
+                            applet_upgrade.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_applet_upgrade: Option<AppletUpgrade> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_applet_upgrade = match original_applet_upgrade {
+                                Some(applet_upgrade) => applet_upgrade,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_applet_upgrade(
+                                action,
+                                applet_upgrade,
+                                original_action,
+                                original_applet_upgrade,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AppletArchival(applet_archival) => {
+                        let result = validate_create_applet_archival(
+                            EntryCreationAction::Update(action.clone()),
+                            applet_archival.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_applet_archival: Option<AppletArchival> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_applet_archival = match original_applet_archival {
+                                Some(applet_archival) => applet_archival,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_applet_archival(
+                                action,
+                                applet_archival,
+                                original_action,
+                                original_applet_archival,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::AppletClonedCellArchival(archival) => {
+                        let result = validate_create_applet_cloned_cell_archival(
+                            EntryCreationAction::Update(action.clone()),
+                            archival.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_archival: Option<AppletClonedCellArchival> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_archival = match original_archival {
+                                Some(archival) => archival,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_applet_cloned_cell_archival(
+                                action,
+                                archival,
+                                original_action,
+                                original_archival,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::SharedAppletClonedCell(shared_cloned_cell) => {
+                        let result = validate_create_shared_applet_cloned_cell(
+                            EntryCreationAction::Update(action.clone()),
+                            shared_cloned_cell.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_shared_cloned_cell: Option<SharedAppletClonedCell> =
+                                original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                            let original_shared_cloned_cell = match original_shared_cloned_cell {
+                                Some(shared_cloned_cell) => shared_cloned_cell,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_shared_applet_cloned_cell(
+                                action,
+                                shared_cloned_cell,
+                                original_action,
+                                original_shared_cloned_cell,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::GroupIcon(group_icon) => {
+                        let result = validate_create_group_icon(
+                            EntryCreationAction::Update(action.clone()),
+                            group_icon.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_group_icon: Option<GroupIcon> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_group_icon = match original_group_icon {
+                                Some(group_icon) => group_icon,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_group_icon(
+                                action,
+                                group_icon,
+                                original_action,
+                                original_group_icon,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::GroupMetaDataSchema(schema) => {
+                        let result = validate_create_group_meta_data_schema(
+                            EntryCreationAction::Update(action.clone()),
+                            schema.clone(),
+                        )?;
+                        if let ValidateCallbackResult::Valid = result {
+                            let original_schema: Option<GroupMetaDataSchema> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_schema = match original_schema {
+                                Some(schema) => schema,
+                                None => {
+                                    return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                }
+                            };
+                            validate_update_group_meta_data_schema(
+                                action,
+                                schema,
+                                original_action,
+                                original_schema,
+                            )
+                        } else {
+                            Ok(result)
+                        }
+                    }
+                    EntryTypes::StewardPermissionRevocationReceipt(_) => {
+                        Ok(ValidateCallbackResult::Invalid(
+                            "StewardPermissionRevocationReceipt entry cannot be updated.".into(),
+                        ))
+                    }
+                    EntryTypes::StewardDeleteAuthorization(_) => {
+                        Ok(ValidateCallbackResult::Invalid(
+                            "StewardDeleteAuthorization entry cannot be updated.".into(),
+                        ))
+                    }
                 }
             }
             OpRecord::DeleteEntry {
@@ -755,6 +1662,72 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             original_group_profile,
                         )
                     }
+                    EntryTypes::StewardPermissionRevocation(original_revocation) => {
+                        validate_delete_steward_permission_revocation(
+                            action,
+                            original_action,
+                            original_revocation,
+                        )
+                    }
+                    EntryTypes::GroupInvitation(original_invitation) => {
+                        validate_delete_group_invitation(
+                            action,
+                            original_action,
+                            original_invitation,
+                        )
+                    }
+                    EntryTypes::AppletUpgrade(original_applet_upgrade) => {
+                        validate_delete_applet_upgrade(
+                            action,
+                            original_action,
+                            original_applet_upgrade,
+                        )
+                    }
+                    EntryTypes::AppletArchival(original_applet_archival) => {
+                        validate_delete_applet_archival(
+                            action,
+                            original_action,
+                            original_applet_archival,
+                        )
+                    }
+                    EntryTypes::AppletClonedCellArchival(original_archival) => {
+                        validate_delete_applet_cloned_cell_archival(
+                            action,
+                            original_action,
+                            original_archival,
+                        )
+                    }
+                    EntryTypes::SharedAppletClonedCell(original_shared_cloned_cell) => {
+                        validate_delete_shared_applet_cloned_cell(
+                            action,
+                            original_action,
+                            original_shared_cloned_cell,
+                        )
+                    }
+                    EntryTypes::GroupIcon(original_group_icon) => {
+                        validate_delete_group_icon(action, original_action, original_group_icon)
+                    }
+                    EntryTypes::GroupMetaDataSchema(original_schema) => {
+                        validate_delete_group_meta_data_schema(
+                            action,
+                            original_action,
+                            original_schema,
+                        )
+                    }
+                    EntryTypes::StewardPermissionRevocationReceipt(original_receipt) => {
+                        validate_delete_steward_permission_revocation_receipt(
+                            action,
+                            original_action,
+                            original_receipt,
+                        )
+                    }
+                    EntryTypes::StewardDeleteAuthorization(original_authorization) => {
+                        validate_delete_steward_delete_authorization(
+                            action,
+                            original_action,
+                            original_authorization,
+                        )
+                    }
                     // Note that a private entry should never show up down here in the first place
                     _ => Ok(ValidateCallbackResult::Invalid(
                         "AppletPrivate match arm should never get called in the first place".into(),
@@ -811,6 +1784,70 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     target_address,
                     tag,
                 ),
+                LinkTypes::StewardPermissionToRevocations => {
+                    validate_create_link_steward_permission_to_revocations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllGroupInvitations => validate_create_link_all_group_invitations(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AppletToUpgrades => validate_create_link_applet_to_upgrades(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AllAppletArchivals => validate_create_link_all_applet_archivals(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::AppletClonedCellToArchivals => {
+                    validate_create_link_applet_cloned_cell_to_archivals(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToSharedAppletClonedCells => {
+                    validate_create_link_agent_to_shared_applet_cloned_cells(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::GroupMetaDataKeyToSchemas => {
+                    validate_create_link_group_meta_data_key_to_schemas(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllGroupMetaDataKeys => validate_create_link_all_group_meta_data_keys(
+                    action,
+                    base_address,
+                    target_address,
+                    tag,
+                ),
+                LinkTypes::ChangeSignalSubscribers => {
+                    validate_create_link_change_signal_subscribers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             },
             OpRecord::DeleteLink {
                 original_action_hash,
@@ -898,6 +1935,81 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             create_link.tag,
                         )
                     }
+                    LinkTypes::StewardPermissionToRevocations => {
+                        validate_delete_link_steward_permission_to_revocations(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllGroupInvitations => validate_delete_link_all_group_invitations(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AppletToUpgrades => validate_delete_link_applet_to_upgrades(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AllAppletArchivals => validate_delete_link_all_applet_archivals(
+                        action,
+                        create_link.clone(),
+                        base_address,
+                        create_link.target_address,
+                        create_link.tag,
+                    ),
+                    LinkTypes::AppletClonedCellToArchivals => {
+                        validate_delete_link_applet_cloned_cell_to_archivals(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AgentToSharedAppletClonedCells => {
+                        validate_delete_link_agent_to_shared_applet_cloned_cells(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::GroupMetaDataKeyToSchemas => {
+                        validate_delete_link_group_meta_data_key_to_schemas(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::AllGroupMetaDataKeys => {
+                        validate_delete_link_all_group_meta_data_keys(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
+                    LinkTypes::ChangeSignalSubscribers => {
+                        validate_delete_link_change_signal_subscribers(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        )
+                    }
                 }
             }
             OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -920,7 +2032,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 match previous_action.action() {
                         Action::AgentValidationPkg(
                             AgentValidationPkg { membrane_proof, .. },
-                        ) => validate_agent_joining(agent, membrane_proof),
+                        ) => validate_agent_joining(agent, membrane_proof, &action.timestamp),
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
@@ -0,0 +1,125 @@
+use hdi::prelude::*;
+
+use crate::Applet;
+
+/// Entry to share a cloned cell of an Applet with a single agent only. The
+/// AppletClonedCell is encrypted for the recipient so that its network seed
+/// is not exposed to the rest of the group.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct SharedAppletClonedCell {
+    pub applet_hash: EntryHash,
+    pub recipient: AgentPubKey,
+    /// The serialized AppletClonedCell, encrypted by the author for the recipient
+    pub encrypted_applet_cloned_cell: XSalsa20Poly1305EncryptedData,
+}
+
+/// Rules
+/// 1. The applet hash must point to a valid Applet entry
+/// 2. Cloned cells cannot be shared with oneself
+pub fn validate_create_shared_applet_cloned_cell(
+    action: EntryCreationAction,
+    shared_cloned_cell: SharedAppletClonedCell,
+) -> ExternResult<ValidateCallbackResult> {
+    let entry = must_get_entry(shared_cloned_cell.applet_hash)?;
+    if Applet::try_from(entry.content).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "applet_hash does not point to an Applet entry.".into(),
+        ));
+    }
+    if &shared_cloned_cell.recipient == action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Cloned cells cannot be shared with oneself.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_shared_applet_cloned_cell(
+    _action: Update,
+    _shared_cloned_cell: SharedAppletClonedCell,
+    _original_action: EntryCreationAction,
+    _original_shared_cloned_cell: SharedAppletClonedCell,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "SharedAppletClonedCell entries cannot be updated",
+    )))
+}
+
+/// Rules
+/// 1. Only the author of the entry can delete it
+pub fn validate_delete_shared_applet_cloned_cell(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_shared_cloned_cell: SharedAppletClonedCell,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a SharedAppletClonedCell entry can delete it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Link must point from the recipient's public key to a SharedAppletClonedCell entry
+/// 2. The creator of the link must be the one that created the SharedAppletClonedCell
+pub fn validate_create_link_agent_to_shared_applet_cloned_cells(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let recipient = base_address
+        .into_agent_pub_key()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Link base is not an agent public key".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let shared_cloned_cell: SharedAppletClonedCell = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a SharedAppletClonedCell entry".to_string()
+        )))?;
+
+    if shared_cloned_cell.recipient != recipient {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link must point away from the recipient of the SharedAppletClonedCell".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of a SharedAppletClonedCell entry can create a link to it".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the creator of the link or the recipient that the link is pointing away
+///    from can delete the link
+pub fn validate_delete_link_agent_to_shared_applet_cloned_cells(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author
+        && base.into_agent_pub_key().as_ref() != Some(&action.author)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator or recipient of an AgentToSharedAppletClonedCells link can delete it."
+                .into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
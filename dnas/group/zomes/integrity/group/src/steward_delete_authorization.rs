@@ -0,0 +1,49 @@
+use hdi::prelude::*;
+
+use crate::{StewardDeleteAuthorization, StewardPermission, UnitEntryTypes};
+
+/// Rules
+/// 1. The permission hash must point to a StewardPermission for the author. Whether it
+///    grants the rights to take the delete is validated with the delete itself.
+pub fn validate_create_steward_delete_authorization(
+    action: EntryCreationAction,
+    authorization: StewardDeleteAuthorization,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(authorization.permission_hash)?;
+    let permission_entry_type: EntryType = UnitEntryTypes::StewardPermission.try_into()?;
+    if record.action().entry_type() != Some(&permission_entry_type) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "permission hash does not point to a StewardPermission entry".into(),
+        ));
+    }
+    let Ok(steward_permission) = StewardPermission::try_from(record) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "permission hash does not point to a valid StewardPermission entry".into(),
+        ));
+    };
+    if &steward_permission.for_agent != action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "StewardPermission is for the wrong agent.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_steward_delete_authorization(
+    _action: Update,
+    _authorization: StewardDeleteAuthorization,
+    _original_action: EntryCreationAction,
+    _original_authorization: StewardDeleteAuthorization,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Delete Authorizations cannot be updated",
+    )))
+}
+pub fn validate_delete_steward_delete_authorization(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_authorization: StewardDeleteAuthorization,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Delete Authorizations cannot be deleted",
+    )))
+}
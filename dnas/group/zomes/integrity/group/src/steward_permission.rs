@@ -1,35 +1,28 @@
 use hdi::prelude::*;
 
-use crate::GroupDnaProperties;
-#[hdk_entry_helper]
-#[derive(Clone, PartialEq)]
-pub struct StewardPermission {
-    pub permission_hash: Option<ActionHash>,
-    pub for_agent: AgentPubKey,
-    pub expiry: Option<Timestamp>,
-}
+use crate::{
+    group_dna_properties, validate_steward_permission, StewardCapability, StewardPermission,
+};
 
 /// Rules
-/// 1. Only the progenitor or agents with a non-expiring StewardPermission can create a StewardPermission
+/// 1. Only the progenitor, founders or agents with a non-expiring StewardPermission can create a StewardPermission
 /// 2. StewardPermissions cannot be created for oneself
-/// 3. The progenitor cannot create a StewardPermission for themselves since they already have maximum permissions
+/// 3. StewardPermissions cannot be created for the progenitor or founders since they already have maximum permissions
+/// 4. Non-expiring StewardPermissions need to be approved by as many founders as the founder threshold requires
+/// 5. Only agents with the ManageStewards capability can create a StewardPermission
+/// 6. Stewards can only grant capabilities that they have been granted themselves
 pub fn validate_create_steward_permission(
     action: EntryCreationAction,
     steward_permission: StewardPermission,
 ) -> ExternResult<ValidateCallbackResult> {
-    let dna_properties =
-        GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
-            wasm_error!(WasmErrorInner::Guest(format!(
-                "Failed to deserialize DNA properties: {e}"
-            )))
-        })?;
-    if let Some(progenitor_b64) = dna_properties.progenitor {
-        let progenitor = AgentPubKey::from(progenitor_b64);
-        if progenitor == steward_permission.for_agent {
-            return Ok(ValidateCallbackResult::Invalid(
-                "StewardPermission entries cannot be created for the progenitor.".into(),
-            ));
-        }
+    let dna_properties = group_dna_properties()?;
+    if dna_properties
+        .founders()
+        .contains(&steward_permission.for_agent)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "StewardPermission entries cannot be created for the progenitor or founders.".into(),
+        ));
     }
     // StewardPermission entries cannot be issued for oneself
     if action.author() == &steward_permission.for_agent {
@@ -37,12 +30,85 @@ pub fn validate_create_steward_permission(
             "StewardPermission entries cannot be created for oneself.".into(),
         ));
     }
-    validate_steward_permission(
+    if steward_permission.expiry.is_none() {
+        let result = validate_founder_threshold(action.author(), &steward_permission)?;
+        if let ValidateCallbackResult::Invalid(_) = result {
+            return Ok(result);
+        }
+    }
+    let result = validate_steward_permission(
         action.author(),
-        steward_permission.permission_hash,
+        action.prev_action(),
+        steward_permission.permission_hash.clone(),
         action.timestamp(),
         false,
-    )
+        StewardCapability::ManageStewards,
+    )?;
+    if let ValidateCallbackResult::Invalid(_) = result {
+        return Ok(result);
+    }
+    if let Some(permission_hash) = steward_permission.permission_hash {
+        if !dna_properties.is_founder(action.author()) {
+            let issuer_permission: StewardPermission = must_get_valid_record(permission_hash)?
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(wasm_error!(WasmErrorInner::Guest(
+                    "permission hash does not point to a valid StewardPermission entry".to_string()
+                )))?;
+            if !issuer_permission.covers_scope(&steward_permission.scope) {
+                return Ok(ValidateCallbackResult::Invalid(
+                    "Stewards can only grant capabilities that they have been granted themselves."
+                        .into(),
+                ));
+            }
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Counts the distinct founders that approved the StewardPermission. The author counts
+/// as an approval if they are a founder themselves.
+fn validate_founder_threshold(
+    author: &AgentPubKey,
+    steward_permission: &StewardPermission,
+) -> ExternResult<ValidateCallbackResult> {
+    let dna_properties = group_dna_properties()?;
+    let threshold = dna_properties.founder_threshold();
+    if threshold <= 1 {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let founders = dna_properties.founders();
+    let mut approvals: Vec<AgentPubKey> = Vec::new();
+    if founders.contains(author) {
+        approvals.push(author.clone());
+    }
+    let payload = steward_permission.signing_payload();
+    for founder_signature in steward_permission.founder_signatures.iter() {
+        if !founders.contains(&founder_signature.founder) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "StewardPermission contains a signature of an agent that is not a founder.".into(),
+            ));
+        }
+        if !verify_signature(
+            founder_signature.founder.clone(),
+            founder_signature.signature.clone(),
+            payload.clone(),
+        )? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "StewardPermission contains an invalid founder signature.".into(),
+            ));
+        }
+        if !approvals.contains(&founder_signature.founder) {
+            approvals.push(founder_signature.founder.clone());
+        }
+    }
+    if approvals.len() < threshold {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "Non-expiring StewardPermissions need to be approved by at least {threshold} founders."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_steward_permission(
     _action: Update,
@@ -60,7 +126,7 @@ pub fn validate_delete_steward_permission(
     _original_steward_permission: StewardPermission,
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Invalid(String::from(
-        "Steward Permissions cannot be deleted",
+        "Steward Permissions cannot be deleted. Create a StewardPermissionRevocation instead.",
     )))
 }
 /// Rules
@@ -109,9 +175,11 @@ pub fn validate_create_link_agent_to_steward_permissions(
 
     validate_steward_permission(
         &action.author,
+        &action.prev_action,
         steward_permission.permission_hash,
         &action.timestamp,
         false,
+        StewardCapability::ManageStewards,
     )
 }
 pub fn validate_delete_link_agent_to_steward_permissions(
@@ -122,11 +190,11 @@ pub fn validate_delete_link_agent_to_steward_permissions(
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(ValidateCallbackResult::Invalid(String::from(
-        "AgentToStewardPermissions links cannot be deleted",
+        "AgentToStewardPermissions links cannot be deleted. Create a StewardPermissionRevocation instead.",
     )))
 }
 /// Rules:
-/// 1. Only agents with StewardPermission or the progenitor can create these links
+/// 1. Only agents with StewardPermission, the progenitor or founders can create these links
 /// 2. Agents with expiring StewardPermissions cannot create these links
 /// 3. The link must point to a valid StewardPermission entry
 /// 4. The link must point away from the all_steward_permissions anchor
@@ -182,9 +250,11 @@ pub fn validate_create_link_all_steward_permissions(
 
     validate_steward_permission(
         &action.author,
+        &action.prev_action,
         steward_permission.permission_hash,
         &action.timestamp,
         false,
+        StewardCapability::ManageStewards,
     )
 }
 pub fn validate_delete_link_all_steward_permissions(
@@ -198,85 +268,3 @@ pub fn validate_delete_link_all_steward_permissions(
         "Links to StewardPermission entries cannot be deleted.".into(),
     ))
 }
-
-pub fn validate_steward_permission(
-    agent: &AgentPubKey,
-    permission_hash: Option<ActionHash>,
-    timestamp: &Timestamp,
-    allow_expiring_permissions: bool,
-) -> ExternResult<ValidateCallbackResult> {
-    let dna_properties =
-        GroupDnaProperties::try_from(dna_info()?.modifiers.properties).map_err(|e| {
-            wasm_error!(WasmErrorInner::Guest(format!(
-                "Failed to deserialize DNA properties: {e}"
-            )))
-        })?;
-
-    match dna_properties.progenitor {
-        Some(progenitor_b64) => {
-            let progenitor = AgentPubKey::from(progenitor_b64);
-            if &progenitor == agent {
-                Ok(ValidateCallbackResult::Valid)
-            } else {
-                match permission_hash {
-                    Some(ph) => {
-                        // get permission record
-                        let permission_record = must_get_valid_record(ph.clone())?;
-
-                        let maybe_steward_permission = match permission_record
-                            .entry()
-                            .to_app_option::<StewardPermission>()
-                        {
-                            Ok(perm) => perm,
-                            Err(_) => return Ok(ValidateCallbackResult::Invalid(
-                                "permission hash does not point to a serializable StewardPermission entry"
-                                    .into(),
-                            )),
-                        };
-
-                        let steward_permission = match maybe_steward_permission {
-                            Some(perm) => perm,
-                            None => return Ok(ValidateCallbackResult::Invalid(
-                                "permission hash does not point to a valid StewardPermission entry"
-                                    .into(),
-                            )),
-                        };
-
-                        // 1. Validate that permission is for the correct agent
-                        if &steward_permission.for_agent != agent {
-                            return Ok(ValidateCallbackResult::Invalid(
-                                "StewardPermission is for the wrong agent.".into(),
-                            ));
-                        }
-
-                        // 2. If the StewardPermission is has an expiry check whether expiring
-                        //    permissions are allowed to take the given action
-                        if let Some(_) = steward_permission.expiry {
-                            if allow_expiring_permissions == false {
-                                return Ok(ValidateCallbackResult::Invalid(
-                                    "Only non-expiring StewardPermissions are allowed to take this action."
-                                        .into(),
-                                ));
-                            }
-                        }
-
-                        // 3. Validate that the permission has not expired
-                        if let Some(expiry) = steward_permission.expiry {
-                            if &expiry < timestamp {
-                                return Ok(ValidateCallbackResult::Invalid(
-                                    "StewardPermission has expired.".into(),
-                                ));
-                            }
-                        }
-
-                        Ok(ValidateCallbackResult::Valid)
-                    }
-                    None => Ok(ValidateCallbackResult::Invalid(
-                        "No valid permission hash provided and agent is not the progenitor".into(),
-                    )),
-                }
-            }
-        }
-        None => Ok(ValidateCallbackResult::Valid),
-    }
-}
@@ -0,0 +1,115 @@
+use hdi::prelude::*;
+
+use crate::{validate_steward_permission, StewardCapability, StewardPermission, UnitEntryTypes};
+
+/// Revokes a previously issued StewardPermission from the timestamp of the
+/// revocation action onward
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct StewardPermissionRevocation {
+    /// ActionHash of the StewardPermission based on which the revocation is issued
+    pub permission_hash: Option<ActionHash>,
+    /// ActionHash of the StewardPermission that gets revoked
+    pub revoked_permission_hash: ActionHash,
+    pub reason: Option<String>,
+}
+
+/// Rules
+/// 1. Only the progenitor, founders or agents with a non-expiring StewardPermission that
+///    grants the ManageStewards capability can revoke a StewardPermission
+/// 2. The revoked action hash must point to a StewardPermission entry
+pub fn validate_create_steward_permission_revocation(
+    action: EntryCreationAction,
+    revocation: StewardPermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(revocation.revoked_permission_hash.clone())?;
+    let permission_entry_type: EntryType = UnitEntryTypes::StewardPermission.try_into()?;
+    if record.action().entry_type() != Some(&permission_entry_type)
+        || StewardPermission::try_from(record).is_err()
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "revoked_permission_hash does not point to a StewardPermission entry.".into(),
+        ));
+    }
+    validate_steward_permission(
+        action.author(),
+        action.prev_action(),
+        revocation.permission_hash,
+        action.timestamp(),
+        false,
+        StewardCapability::ManageStewards,
+    )
+}
+pub fn validate_update_steward_permission_revocation(
+    _action: Update,
+    _revocation: StewardPermissionRevocation,
+    _original_action: EntryCreationAction,
+    _original_revocation: StewardPermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Permission Revocations cannot be updated",
+    )))
+}
+pub fn validate_delete_steward_permission_revocation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_revocation: StewardPermissionRevocation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Permission Revocations cannot be deleted",
+    )))
+}
+
+/// Rules
+/// 1. Link must point from the revoked StewardPermission to a StewardPermissionRevocation entry
+/// 2. The revocation must be for the StewardPermission that the link is pointing away from
+/// 3. The creator of the link must be the one that created the revocation
+pub fn validate_create_link_steward_permission_to_revocations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let revoked_permission_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link base is not an action hash".to_string()
+            )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let revocation: StewardPermissionRevocation = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a StewardPermissionRevocation entry".to_string()
+        )))?;
+
+    if revocation.revoked_permission_hash != revoked_permission_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Link is pointing to a revocation of a different StewardPermission".into(),
+        ));
+    }
+
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid("Only the creator of a StewardPermissionRevocation entry can create a link from the revoked StewardPermission to it".into()));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_steward_permission_to_revocations(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "StewardPermissionToRevocations links cannot be deleted",
+    )))
+}
@@ -0,0 +1,43 @@
+use hdi::prelude::*;
+
+use crate::{StewardPermissionRevocation, StewardPermissionRevocationReceipt};
+
+/// Rules
+/// 1. The revocation hash must point to a StewardPermissionRevocation of the revoked
+///    permission hash
+pub fn validate_create_steward_permission_revocation_receipt(
+    _action: EntryCreationAction,
+    receipt: StewardPermissionRevocationReceipt,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(receipt.revocation_hash)?;
+    let Ok(revocation) = StewardPermissionRevocation::try_from(record) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "revocation hash does not point to a StewardPermissionRevocation entry".into(),
+        ));
+    };
+    if revocation.revoked_permission_hash != receipt.revoked_permission_hash {
+        return Ok(ValidateCallbackResult::Invalid(
+            "StewardPermissionRevocationReceipt is for a different StewardPermission than the revocation".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_steward_permission_revocation_receipt(
+    _action: Update,
+    _receipt: StewardPermissionRevocationReceipt,
+    _original_action: EntryCreationAction,
+    _original_receipt: StewardPermissionRevocationReceipt,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Permission Revocation Receipts cannot be updated",
+    )))
+}
+pub fn validate_delete_steward_permission_revocation_receipt(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_receipt: StewardPermissionRevocationReceipt,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "Steward Permission Revocation Receipts cannot be deleted",
+    )))
+}
@@ -0,0 +1,106 @@
+use hdi::prelude::*;
+
+/// Record that agents can opt in to publish so that peers that have been offline
+/// while they were online can still see their recent activity
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct LastSeen {
+    pub last_seen: Timestamp,
+}
+
+/// Rules
+/// 1. The last seen timestamp must not lie in the future relative to the action's timestamp
+pub fn validate_create_last_seen(
+    action: EntryCreationAction,
+    last_seen: LastSeen,
+) -> ExternResult<ValidateCallbackResult> {
+    if &last_seen.last_seen > action.timestamp() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The last seen timestamp must not lie in the future.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_last_seen(
+    _action: Update,
+    _last_seen: LastSeen,
+    _original_action: EntryCreationAction,
+    _original_last_seen: LastSeen,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from(
+        "LastSeen entries cannot be updated",
+    )))
+}
+
+/// Rules
+/// 1. Only the author of a LastSeen entry can delete it
+pub fn validate_delete_last_seen(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_last_seen: LastSeen,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a LastSeen entry can delete it.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Link must point away from the public key of the creator of the link
+/// 2. Link must point to a LastSeen entry created by the creator of the link
+pub fn validate_create_link_agent_to_last_seen(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let agent = base_address
+        .into_agent_pub_key()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Link base is not an agent public key".to_string()
+        )))?;
+    if agent != action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToLastSeen links must point away from the public key of their creator".into(),
+        ));
+    }
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Link target is not an action hash".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _last_seen: LastSeen = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference a LastSeen entry".to_string()
+        )))?;
+    if record.action().author() != &action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToLastSeen links must point to a LastSeen entry of their creator".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rules
+/// 1. Only the creator of the link can delete the link
+pub fn validate_delete_link_agent_to_last_seen(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the creator of an AgentToLastSeen link can delete that link.".into(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
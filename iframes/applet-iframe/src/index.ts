@@ -128,10 +128,11 @@ const weaveApi: WeaveServices = {
         dstWal,
         tags,
       }),
-    removeAssetRelation: (relationHash) =>
+    removeAssetRelation: (relationHash, reason) =>
       postMessage({
         type: 'remove-asset-relation',
         relationHash,
+        reason,
       }),
     addTagsToAssetRelation: (relationHash, tags) =>
       postMessage({
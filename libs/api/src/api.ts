@@ -278,10 +278,14 @@ export interface AssetServices {
    * no value because Tool frontends should subscribe to the AssetStore(s) to update
    * their frontend state.
    *
+   * Only the creator of an asset relation, agents delegated by them and stewards of
+   * the group can remove it. The removal gets recorded together with the reason.
+   *
    * @param relationHash
+   * @param reason
    * @returns
    */
-  removeAssetRelation: (relationHash: EntryHash) => Promise<void>;
+  removeAssetRelation: (relationHash: EntryHash, reason?: string) => Promise<void>;
   /**
    * Adds new tags to an existing asset relation
    *
@@ -562,8 +566,8 @@ export class WeaveClient implements WeaveServices {
       window.__WEAVE_API__.assets.removeTagsFromAsset(wal, tags),
     addAssetRelation: (srcWal: WAL, dstWal: WAL, tags?: string[]) =>
       window.__WEAVE_API__.assets.addAssetRelation(srcWal, dstWal, tags),
-    removeAssetRelation: (relationHash: EntryHash) =>
-      window.__WEAVE_API__.assets.removeAssetRelation(relationHash),
+    removeAssetRelation: (relationHash: EntryHash, reason?: string) =>
+      window.__WEAVE_API__.assets.removeAssetRelation(relationHash, reason),
     addTagsToAssetRelation: (relationHash: EntryHash, tags: string[]) =>
       window.__WEAVE_API__.assets.addTagsToAssetRelation(relationHash, tags),
     removeTagsFromAssetRelation: (relationHash: EntryHash, tags: string[]) =>
@@ -491,6 +491,7 @@ export type AppletToParentRequest =
   | {
       type: 'remove-asset-relation';
       relationHash: EntryHash;
+      reason?: string;
     }
   | {
       type: 'add-tags-to-asset-relation';
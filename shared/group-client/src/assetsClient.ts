@@ -1,12 +1,23 @@
-import { ZomeClient } from '@holochain-open-dev/utils';
-import { EntryHash, AppClient } from '@holochain/client';
+import { EntryRecord, ZomeClient } from '@holochain-open-dev/utils';
+import {
+  ActionHash,
+  AgentPubKey,
+  AppClient,
+  EntryHash,
+  Record as HolochainRecord,
+} from '@holochain/client';
 import { WAL, WalRelationAndTags } from '@theweave/api';
 
 import {
   AssetRelationAndHash,
+  AssetRelationTombstone,
   AssetRelationWithTags,
+  DelegateAssetRelationRemovalInput,
+  Page,
+  PaginationInput,
   RelateAssetsInput,
   RelationsForWal,
+  RemoveAssetRelationInput,
   SignalPayloadAssets,
   TagsToAssetInput,
 } from './types.js';
@@ -121,14 +132,78 @@ export class AssetsClient extends ZomeClient<SignalPayloadAssets> {
   }
 
   /**
+   * Removes an asset relation and records an AssetRelationTombstone with the reason
+   * for the removal
    *
    * @param relationHash
+   * @param reason
    * @param local Whether to use `GetStrategy::Local` or not. The `GetStrategy` is being used
    * amongst others to get all the links to be deleted.
    * @returns
    */
-  async removeAssetRelation(relationHash: EntryHash, local: boolean = true): Promise<void> {
-    return this.callZome('remove_asset_relation', { input: relationHash, local });
+  async removeAssetRelation(
+    relationHash: EntryHash,
+    reason?: string,
+    local: boolean = true,
+  ): Promise<void> {
+    const input: RemoveAssetRelationInput = { relation_hash: relationHash, reason };
+    return this.callZome('remove_asset_relation', { input, local });
+  }
+
+  /**
+   * Delegates the removal of the asset relations created by the calling agent to
+   * another agent until the delegation expires
+   *
+   * @param delegate
+   * @param expiry Microseconds since the Unix Epoch. Defaults to the maximum duration
+   * of a delegation (90 days) from now.
+   * @returns
+   */
+  async delegateAssetRelationRemoval(delegate: AgentPubKey, expiry?: number): Promise<ActionHash> {
+    const input: DelegateAssetRelationRemovalInput = { delegate, expiry };
+    return this.callZome('delegate_asset_relation_removal', input);
+  }
+
+  /**
+   * Revokes the delegations to the given agent. Validation only enforces the expiry of
+   * a delegation, so a dishonest delegate may keep using it until then.
+   *
+   * @param delegate
+   * @returns
+   */
+  async revokeAssetRelationRemovalDelegation(delegate: AgentPubKey): Promise<void> {
+    return this.callZome('revoke_asset_relation_removal_delegation', delegate);
+  }
+
+  /**
+   *
+   * @param agent
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getAssetRelationRemovalDelegates(
+    agent: AgentPubKey,
+    local?: boolean,
+  ): Promise<AgentPubKey[]> {
+    return this.callZome('get_asset_relation_removal_delegates', { input: agent, local });
+  }
+
+  /**
+   * Gets the records of the removals of an asset relation, oldest first
+   *
+   * @param relationHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getAssetRelationTombstones(
+    relationHash: EntryHash,
+    local?: boolean,
+  ): Promise<EntryRecord<AssetRelationTombstone>[]> {
+    const records: HolochainRecord[] = await this.callZome('get_asset_relation_tombstones', {
+      input: relationHash,
+      local,
+    });
+    return records.map((record) => new EntryRecord(record));
   }
 
   /**
@@ -313,6 +388,26 @@ export class AssetsClient extends ZomeClient<SignalPayloadAssets> {
     return decodeAssetRelationsWALs(assetRelationsAndHash);
   }
 
+  /**
+   *
+   * @param pagination
+   * @param local Whether to use `GetStrategy::Local` or not
+   * @returns
+   */
+  async getAllAssetRelationsPage(
+    pagination: PaginationInput,
+    local: boolean = true,
+  ): Promise<Page<AssetRelationAndHash>> {
+    const page = await this.callZome('get_all_asset_relations_page', {
+      input: pagination,
+      local,
+    });
+    return {
+      items: decodeAssetRelationsWALs(page.items),
+      next_cursor: page.next_cursor,
+    };
+  }
+
   /**
    *
    * @param local Whether to use `GetStrategy::Local` or not
@@ -325,6 +420,27 @@ export class AssetsClient extends ZomeClient<SignalPayloadAssets> {
     });
     return decodeAssetRelationsWALs(assetRelationsWithTags) as AssetRelationWithTags[];
   }
+
+  /**
+   * Gets an asset relation with its tags and the agents that have asserted it or
+   * added its tags
+   *
+   * @param relationHash
+   * @param local Whether to use `GetStrategy::Local` or not
+   * @returns
+   */
+  async getAssetRelationWithTags(
+    relationHash: EntryHash,
+    local: boolean = true,
+  ): Promise<AssetRelationWithTags | undefined> {
+    const assetRelationWithTags = await this.callZome('get_asset_relation_with_tags', {
+      input: relationHash,
+      local,
+    });
+    return assetRelationWithTags
+      ? (decodeAssetRelationWALs(assetRelationWithTags) as AssetRelationWithTags)
+      : undefined;
+  }
 }
 
 /**
@@ -373,6 +489,8 @@ export function decodeAssetRelationWALs(
       tags: relationWithTags.tags,
       relation_hash: relationWithTags.relation_hash,
       created_at: relationWithTags.created_at,
+      endorsements: relationWithTags.endorsements,
+      tag_endorsements: relationWithTags.tag_endorsements,
     }
     : {
       src_wal: walDecodeContext(relationWithTags.src_wal),
@@ -16,17 +16,36 @@ import { encode } from '@msgpack/msgpack';
 import {
   Applet,
   AppletAgent,
+  DanglingJoinedLink,
   GROUP_APPLETS_META_DATA_NAME,
   GROUP_DESCRIPTION_NAME,
   GroupAppletsMetaData,
   GroupMetaData,
+  GroupMetaDataSchema,
+  RevertGroupMetaDataInput,
   JoinAppletInput,
   AppletEntryPrivate,
+  AppletUpgrade,
+  AppletArchival,
+  ArchiveAppletInput,
   StewardPermission,
+  StewardPermissionRevocation,
+  FounderSignature,
+  CreateGroupInvitationInput,
+  GroupInvitation,
+  GroupMembraneProof,
   AppletClonedCell,
+  AppletClonedCellArchival,
+  ArchiveClonedCellInput,
+  ShareClonedCellInput,
+  ClonedCellSharedWithMe,
   GroupRemoteSignal,
   SignalPayloadGroup,
-  GroupProfile, Accountability
+  GroupSignal,
+  GroupProfile, Accountability,
+  GroupIcon,
+  Page,
+  PaginationInput,
 } from './types.js';
 
 export class GroupClient {
@@ -57,6 +76,25 @@ export class GroupClient {
     });
   }
 
+  /**
+   * Listens to GroupSignals from other group members
+   *
+   * @param topic If provided, only signals of this topic are passed to the listener
+   * @param listener
+   * @returns
+   */
+  onGroupSignal(
+    topic: string | undefined,
+    listener: (groupSignal: GroupSignal) => void | Promise<void>,
+  ): UnsubscribeFunction {
+    return this.onSignal((signal) => {
+      if (signal.type === 'Group' && (!topic || signal.topic === topic)) {
+        const { type, ...groupSignal } = signal;
+        listener(groupSignal);
+      }
+    });
+  }
+
   /**
    * =============================================================================================
    * Group Profile
@@ -80,6 +118,56 @@ export class GroupClient {
     await this.callZome('set_group_profile', groupProfile);
   }
 
+  /**
+   * Gets all revisions of the group profile, ordered from the first to the latest one
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getGroupProfileHistory(local: boolean = true): Promise<Array<EntryRecord<GroupProfile>>> {
+    const records: Record[] = await this.callZome('get_group_profile_history', {
+      input: null,
+      local,
+    });
+    return records.map((record) => new EntryRecord(record));
+  }
+
+  /**
+   * Stores a group icon. Identical icons are only stored once.
+   *
+   * @param groupIcon
+   * @returns The entry hash of the icon, to be referenced by the group profile
+   */
+  async createGroupIcon(groupIcon: GroupIcon): Promise<EntryHash> {
+    return this.callZome('create_group_icon', groupIcon);
+  }
+
+  /**
+   *
+   * @param iconHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getGroupIcon(iconHash: EntryHash, local: boolean = true): Promise<GroupIcon | undefined> {
+    return this.callZome('get_group_icon', { input: iconHash, local });
+  }
+
+  /**
+   * Fills in the icon_src of a group profile from the GroupIcon that it references, if any
+   *
+   * @param groupProfile
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async resolveGroupProfileIcon(
+    groupProfile: GroupProfile,
+    local: boolean = true,
+  ): Promise<GroupProfile> {
+    if (!groupProfile.icon_hash) return groupProfile;
+    const groupIcon = await this.getGroupIcon(groupProfile.icon_hash, local);
+    return groupIcon ? { ...groupProfile, icon_src: groupIcon.src } : groupProfile;
+  }
+
   /**
    * =============================================================================================
    * Applets
@@ -115,6 +203,21 @@ export class GroupClient {
     return this.callZome('get_group_applets', { input: null, local });
   }
 
+  /**
+   * Gets a page of the unarchived Applets of the group. Pages can contain fewer
+   * Applets than the limit even if they are not the last page.
+   *
+   * @param pagination
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getGroupAppletsPage(
+    pagination: PaginationInput,
+    local: boolean = true,
+  ): Promise<Page<EntryHash>> {
+    return this.callZome('get_group_applets_page', { input: pagination, local });
+  }
+
   /**
    * Gets all the private Applet entries from the source chain
    * @returns
@@ -193,6 +296,26 @@ export class GroupClient {
     return this.callZome('get_joined_applet_agents', { input: appletHash, local });
   }
 
+  /**
+   * Resolves the public key that an agent uses in an applet back to their
+   * public key in the group
+   *
+   * @param appletHash
+   * @param appletPubkey
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getGroupPubkeyForAppletAgent(
+    appletHash: EntryHash,
+    appletPubkey: AgentPubKey,
+    local: boolean = true,
+  ): Promise<AgentPubKey | undefined> {
+    return this.callZome('get_group_pubkey_for_applet_agent', {
+      input: [appletHash, appletPubkey],
+      local,
+    });
+  }
+
   /**
    *
    * @param appletHash
@@ -206,6 +329,27 @@ export class GroupClient {
     return this.callZome('get_abandoned_applet_agents', { input: appletHash, local });
   }
 
+  /**
+   * Gets the AppletToJoinedAgent links of this agent that do not point away from
+   * a valid applet entry
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getMyDanglingJoinedLinks(local: boolean = true): Promise<Array<DanglingJoinedLink>> {
+    return this.callZome('get_my_dangling_joined_links', { input: null, local });
+  }
+
+  /**
+   * Deletes the given AppletToJoinedAgent links of this agent
+   *
+   * @param createLinkHashes
+   * @returns
+   */
+  async removeDanglingJoinedLinks(createLinkHashes: Array<ActionHash>): Promise<void> {
+    return this.callZome('remove_dangling_joined_links', createLinkHashes);
+  }
+
   /**
    * Advertises the Applet in the group DNA, adds the Applet entry as a private
    * entry to the source chain and creates links from the applet to the public
@@ -252,17 +396,94 @@ export class GroupClient {
   }
 
   /**
+   * Archives an applet, meaning that it won't be discovered by default anymore
+   * by agents that have not installed it yet.
+   *
+   * @param appletHash
+   * @param permissionHash ActionHash of the StewardPermission of the calling agent
+   * @param reason
+   * @returns
+   */
+  async archiveApplet(
+    appletHash: EntryHash,
+    permissionHash?: ActionHash,
+    reason?: string,
+  ): Promise<EntryRecord<AppletArchival>> {
+    const input: ArchiveAppletInput = {
+      applet_hash: appletHash,
+      permission_hash: permissionHash,
+      reason,
+    };
+    const response: Record = await this.callZome('archive_applet', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   *
+   * @param appletHash
+   * @param permissionHash ActionHash of the StewardPermission of the calling agent
+   * @param reason
+   * @returns
+   */
+  async unarchiveApplet(
+    appletHash: EntryHash,
+    permissionHash?: ActionHash,
+    reason?: string,
+  ): Promise<EntryRecord<AppletArchival>> {
+    const input: ArchiveAppletInput = {
+      applet_hash: appletHash,
+      permission_hash: permissionHash,
+      reason,
+    };
+    const response: Record = await this.callZome('unarchive_applet', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   * Gets the history of archivals and unarchivals of an applet
    *
    * @param appletHash
    * @param local Whether to use GetStrategy::Local or not
    * @returns
    */
-  async archiveApplet(appletHash: EntryHash, local: boolean = true): Promise<void> {
-    return this.callZome('archive_applet', { input: appletHash, local });
+  async getAppletArchivals(
+    appletHash: AppletHash,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<AppletArchival>>> {
+    const records: Record[] = await this.callZome('get_applet_archivals', {
+      input: appletHash,
+      local,
+    });
+    return records.map((record) => new EntryRecord(record));
   }
 
-  async unarchiveApplet(appletHash: EntryHash): Promise<void> {
-    return this.callZome('unarchive_applet', appletHash);
+  /**
+   * Records an upgrade of an applet to a new happ version
+   *
+   * @param input
+   * @returns
+   */
+  async upgradeApplet(input: AppletUpgrade): Promise<EntryRecord<AppletUpgrade>> {
+    const response: Record = await this.callZome('upgrade_applet', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   * Gets the upgrades of an applet ordered from the first to the latest one
+   *
+   * @param appletHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getAppletUpgradeChain(
+    appletHash: AppletHash,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<AppletUpgrade>>> {
+    const records: Record[] = await this.callZome('get_applet_upgrade_chain', {
+      input: appletHash,
+      local,
+    });
+    return records.map((record) => new EntryRecord(record));
   }
 
   /**
@@ -301,6 +522,49 @@ export class GroupClient {
     return undefined;
   }
 
+  /**
+   * Approves a non-expiring StewardPermission. Can only be called by founders.
+   * The returned signature needs to be added to the `founder_signatures` of the
+   * StewardPermission before it gets created.
+   *
+   * @param input
+   * @returns
+   */
+  async signStewardPermission(input: StewardPermission): Promise<FounderSignature> {
+    return this.callZome('sign_steward_permission', input);
+  }
+
+  /**
+   * Revokes a StewardPermission. Can only be called by the Progenitor or Stewards
+   * with a non-expiring permission.
+   *
+   * @param input
+   * @returns
+   */
+  async revokeStewardPermission(
+    input: StewardPermissionRevocation,
+  ): Promise<EntryRecord<StewardPermissionRevocation>> {
+    const response: Record = await this.callZome('revoke_steward_permission', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   *
+   * @param permissionHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getStewardPermissionRevocations(
+    permissionHash: ActionHash,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<StewardPermissionRevocation>>> {
+    const response: Record[] = await this.callZome('get_steward_permission_revocations', {
+      input: permissionHash,
+      local,
+    });
+    return response.map((record) => new EntryRecord(record));
+  }
+
   /**
    * @param ts Timestamp in ms since the Unix Epoch.
    * @param local Whether to use GetStrategy::Local or not
@@ -323,6 +587,26 @@ export class GroupClient {
     return this.callZome('get_agent_accountabilities', { input: [agent, timestamp], local });
   }
 
+  /**
+   * Gets the accountabilities that the agent has right now based on the given
+   * StewardPermission, regardless of when the agent claims to have acted based on it
+   *
+   * @param agent
+   * @param permissionHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getAgentAccountabilitiesByPermission(
+    agent: AgentPubKey,
+    permissionHash: ActionHash | undefined,
+    local: boolean = true,
+  ): Promise<Accountability[]> {
+    return this.callZome('get_agent_accountabilities_by_permission', {
+      input: [agent, permissionHash],
+      local,
+    });
+  }
+
   /**
    * @param ts Timestamp in ms since the Unix Epoch.
    * @param local Whether to use GetStrategy::Local or not
@@ -336,6 +620,57 @@ export class GroupClient {
     return this.callZome('get_all_agents_accountabilities', { input: timestamp, local });
   }
 
+  /**
+   * =============================================================================================
+   * Invitations
+   * =============================================================================================
+   */
+
+  /**
+   * Issues an invitation for the given agent. Can only be called by the Progenitor or
+   * Stewards.
+   *
+   * @param input
+   * @returns The membrane proof that the invited agent needs to provide to join the group
+   */
+  async createGroupInvitation(input: CreateGroupInvitationInput): Promise<GroupMembraneProof> {
+    return this.callZome('create_group_invitation', input);
+  }
+
+  /**
+   * Hands out a new membrane proof for an invitation that the calling agent has issued.
+   * Membrane proofs are only accepted for a day after they have been handed out.
+   *
+   * @param invitationHash
+   * @returns
+   */
+  async getGroupMembraneProof(invitationHash: ActionHash): Promise<GroupMembraneProof> {
+    return this.callZome('get_group_membrane_proof', invitationHash);
+  }
+
+  /**
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getGroupInvitations(local: boolean = true): Promise<Array<EntryRecord<GroupInvitation>>> {
+    const records: Record[] = await this.callZome('get_group_invitations', { input: null, local });
+    return records.map((record) => new EntryRecord(record));
+  }
+
+  /**
+   * Deletes the invitation. Can only be called by the issuer of the invitation or the
+   * Progenitor. If the issuer deletes it, membrane proofs for it are rejected, except for
+   * ones that have been handed out before the delete, which stay valid for up to a day.
+   *
+   * @param invitationHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async deleteGroupInvitation(invitationHash: ActionHash, local: boolean = true): Promise<void> {
+    return this.callZome('delete_group_invitation', { input: invitationHash, local });
+  }
+
   /**
    * =============================================================================================
    * Group Metadata
@@ -401,6 +736,79 @@ export class GroupClient {
     return new EntryRecord(record);
   }
 
+  /**
+   *
+   * @param name
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns All revisions of the GroupMetaData of the given key, ordered by their position
+   * in the update chain from oldest to newest
+   */
+  async getGroupMetaDataHistory(
+    name: string,
+    local: boolean = true,
+  ): Promise<EntryRecord<GroupMetaData>[]> {
+    const records = await this.callZome<Record[]>('get_group_meta_data_history', {
+      input: name,
+      local,
+    });
+    return records.map((record) => new EntryRecord(record));
+  }
+
+  /**
+   * Re-publishes an earlier revision of the GroupMetaData of a key as its latest revision.
+   * The revert updates the latest revision, so it supersedes all revisions before it in the
+   * update chain.
+   *
+   * @param input
+   * @returns
+   */
+  async revertGroupMetaData(
+    input: RevertGroupMetaDataInput,
+  ): Promise<EntryRecord<GroupMetaData>> {
+    const record = await this.callZome<Record>('revert_group_meta_data', input);
+    return new EntryRecord(record);
+  }
+
+  /**
+   * Sets the schema that GroupMetaData of the given key is validated against. Updates the
+   * current schema of the key if there is one.
+   *
+   * @param schema
+   * @returns
+   */
+  async setGroupMetaDataSchema(
+    schema: GroupMetaDataSchema,
+  ): Promise<EntryRecord<GroupMetaDataSchema>> {
+    const record = await this.callZome<Record>('set_group_meta_data_schema', schema);
+    return new EntryRecord(record);
+  }
+
+  /**
+   *
+   * @param name
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns The current schema of the given key, i.e. its latest revision in the update chain
+   */
+  async getGroupMetaDataSchema(
+    name: string,
+    local: boolean = true,
+  ): Promise<EntryRecord<GroupMetaDataSchema> | undefined> {
+    const record = await this.callZome<Record | undefined>('get_group_meta_data_schema', {
+      input: name,
+      local,
+    });
+    return record ? new EntryRecord(record) : undefined;
+  }
+
+  /**
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns The names of all GroupMetaData keys for which data or a schema exists
+   */
+  async listGroupMetaDataKeys(local: boolean = true): Promise<string[]> {
+    return this.callZome('list_group_meta_data_keys', { input: null, local });
+  }
+
   /**
    * =============================================================================================
    * Cloned Cells
@@ -474,6 +882,112 @@ export class GroupClient {
     return this.callZome('get_unjoined_cloned_cells_for_applet', { input: appletHash, local });
   }
 
+  /**
+   * Archives a cloned cell, meaning that it won't be discovered by default anymore
+   * by agents that have not joined it yet.
+   *
+   * @param appletClonedCellHash
+   * @param permissionHash ActionHash of the StewardPermission of the calling agent
+   * @param reason
+   * @returns
+   */
+  async archiveClonedCell(
+    appletClonedCellHash: EntryHash,
+    permissionHash?: ActionHash,
+    reason?: string,
+  ): Promise<EntryRecord<AppletClonedCellArchival>> {
+    const input: ArchiveClonedCellInput = {
+      applet_cloned_cell_hash: appletClonedCellHash,
+      permission_hash: permissionHash,
+      reason,
+    };
+    const response: Record = await this.callZome('archive_cloned_cell', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   *
+   * @param appletClonedCellHash
+   * @param permissionHash ActionHash of the StewardPermission of the calling agent
+   * @param reason
+   * @returns
+   */
+  async unarchiveClonedCell(
+    appletClonedCellHash: EntryHash,
+    permissionHash?: ActionHash,
+    reason?: string,
+  ): Promise<EntryRecord<AppletClonedCellArchival>> {
+    const input: ArchiveClonedCellInput = {
+      applet_cloned_cell_hash: appletClonedCellHash,
+      permission_hash: permissionHash,
+      reason,
+    };
+    const response: Record = await this.callZome('unarchive_cloned_cell', input);
+    return new EntryRecord(response);
+  }
+
+  /**
+   *
+   * @param appletHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getArchivedClonedCellsForApplet(
+    appletHash: EntryHash,
+    local: boolean = true,
+  ): Promise<EntryHash[]> {
+    return this.callZome('get_archived_cloned_cells_for_applet', { input: appletHash, local });
+  }
+
+  /**
+   * Shares a cloned cell privately with the given agents only, without registering
+   * it publicly in the group DNA
+   *
+   * @param appletClonedCell
+   * @param recipients
+   * @returns
+   */
+  async shareClonedCell(
+    appletClonedCell: AppletClonedCell,
+    recipients: AgentPubKey[],
+  ): Promise<EntryHash> {
+    const input: ShareClonedCellInput = {
+      applet_cloned_cell: appletClonedCell,
+      recipients,
+    };
+    return this.callZome('share_cloned_cell', input);
+  }
+
+  /**
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getClonedCellsSharedWithMe(local: boolean = true): Promise<ClonedCellSharedWithMe[]> {
+    return this.callZome('get_cloned_cells_shared_with_me', { input: null, local });
+  }
+
+  /**
+   * Removes a cloned cell that has been shared with this agent from the cloned cells
+   * returned by getClonedCellsSharedWithMe
+   *
+   * @param sharedClonedCellHash ActionHash of the SharedAppletClonedCell entry
+   * @returns
+   */
+  async dismissSharedClonedCell(sharedClonedCellHash: ActionHash): Promise<void> {
+    return this.callZome('dismiss_shared_cloned_cell', sharedClonedCellHash);
+  }
+
+  /**
+   * Stores a private copy of a cloned cell that has been shared with this agent
+   *
+   * @param appletClonedCell
+   * @returns
+   */
+  async joinSharedClonedCell(appletClonedCell: AppletClonedCell): Promise<EntryHash> {
+    return this.callZome('join_shared_cloned_cell', appletClonedCell);
+  }
+
   /**
    * =============================================================================================
    * General stuff
@@ -490,6 +1004,50 @@ export class GroupClient {
     });
   }
 
+  /**
+   * Opts in to receive signals about changes to public data of the group from other
+   * members. Changes are received via onSignal.
+   */
+  async subscribeToChangeSignals(): Promise<void> {
+    return this.callZome('subscribe_to_change_signals', null);
+  }
+
+  async unsubscribeFromChangeSignals(): Promise<void> {
+    return this.callZome('unsubscribe_from_change_signals', null);
+  }
+
+  /**
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns All agents that subscribed to change signals
+   */
+  async getChangeSignalSubscribers(local: boolean = true): Promise<AgentPubKey[]> {
+    return this.callZome('get_change_signal_subscribers', { input: null, local });
+  }
+
+  /**
+   * Send a GroupSignal to peers via remote signal
+   *
+   * @param topic Topic that receivers can filter by
+   * @param content
+   * @param toAgents
+   * @param appletHash The Applet that the signal is scoped to, if any
+   * @returns The message id of the signal
+   */
+  async sendGroupSignal(
+    topic: string,
+    content: Uint8Array,
+    toAgents: AgentPubKey[],
+    appletHash?: EntryHash,
+  ): Promise<string> {
+    return this.callZome('send_group_signal', {
+      to_agents: toAgents,
+      topic,
+      applet_hash: appletHash,
+      content,
+    });
+  }
+
   private callZome<T>(fn_name: string, payload: any) {
     const req: RoleNameCallZomeRequest = {
       role_name: this.roleName,
@@ -1,7 +1,15 @@
 import { ZomeClient } from '@holochain-open-dev/utils';
-import { AgentPubKey, AppClient, RoleName } from '@holochain/client';
+import {
+  AgentPubKey,
+  AgentPubKeyB64,
+  AppClient,
+  encodeHashToBase64,
+  RoleName,
+  Timestamp,
+} from '@holochain/client';
+import { UnsubscribeFunction } from '@theweave/api';
 
-import { SignalPayloadPeerStatus } from './types.js';
+import { PeerLastSeen, Presence, SignalPayloadPeerStatus } from './types.js';
 
 export class PeerStatusClient extends ZomeClient<SignalPayloadPeerStatus> {
   constructor(
@@ -12,25 +20,100 @@ export class PeerStatusClient extends ZomeClient<SignalPayloadPeerStatus> {
     super(client, roleName, zomeName);
   }
 
+  /**
+   * Minimum interval between two pings of the same peer. Pings arriving more frequently
+   * are dropped. The zome rate limits signals per peer as well, but only on a best-effort
+   * basis since its wasm instances may be re-created at any time.
+   */
+  minPingIntervalMs = 3000;
+
+  onSignal(
+    listener: (eventData: SignalPayloadPeerStatus) => void | Promise<void>,
+  ): UnsubscribeFunction {
+    const lastPingReceived: Record<AgentPubKeyB64, number> = {};
+    return super.onSignal((signal) => {
+      if (signal.type === 'Ping') {
+        const agentB64 = encodeHashToBase64(signal.from_agent);
+        const now = Date.now();
+        const previous = lastPingReceived[agentB64];
+        if (previous && now - previous < this.minPingIntervalMs) return;
+        lastPingReceived[agentB64] = now;
+      }
+      return listener(signal);
+    });
+  }
+
   /**
    * Ping all specified agents, expecting for their pong later
+   *
+   * @param fanout If provided, only this many randomly chosen agents of the specified
+   * agents get pinged. The others are learned about via the digests in the pongs.
    */
-  async ping(agentPubKeys: AgentPubKey[], status, tzUtcOffset?: number): Promise<void> {
+  async ping(
+    agentPubKeys: AgentPubKey[],
+    status,
+    tzUtcOffset?: number,
+    presence?: Presence,
+    fanout?: number,
+  ): Promise<void> {
     return this.callZome('ping', {
       to_agents: agentPubKeys,
       status,
       tz_utc_offset: tzUtcOffset,
+      presence,
+      fanout,
     });
   }
 
   /**
    * Pong all specified agents
+   *
+   * @param digest Peers that this agent has recently heard from
    */
-  async pong(agentPubKeys: AgentPubKey[], status, tzUtcOffset?: number): Promise<void> {
+  async pong(
+    agentPubKeys: AgentPubKey[],
+    status,
+    tzUtcOffset?: number,
+    presence?: Presence,
+    digest: PeerLastSeen[] = [],
+  ): Promise<void> {
     return this.callZome('pong', {
       to_agents: agentPubKeys,
       status,
       tz_utc_offset: tzUtcOffset,
+      presence,
+      digest,
     });
   }
+
+  /**
+   * Merge the digests received in pongs into a single roster with the latest time
+   * that each agent has been seen. Agents that have not appeared in the network of
+   * the group are dropped.
+   */
+  async getPresenceSnapshot(digests: PeerLastSeen[][]): Promise<PeerLastSeen[]> {
+    return this.callZome('get_presence_snapshot', { digests });
+  }
+
+  /**
+   * Publish a "last seen" record to the DHT so that peers that are currently offline
+   * can see this agent's recent activity later
+   */
+  async publishLastSeen(): Promise<void> {
+    return this.callZome('publish_last_seen', null);
+  }
+
+  /**
+   * Remove all previously published "last seen" records from the DHT
+   */
+  async unpublishLastSeen(): Promise<void> {
+    return this.callZome('unpublish_last_seen', null);
+  }
+
+  /**
+   * Get the last time the given agent has been seen, if they opted in to publish it
+   */
+  async getLastSeen(agent: AgentPubKey): Promise<Timestamp | undefined> {
+    return this.callZome('get_last_seen', agent);
+  }
 }
@@ -2,10 +2,16 @@ import {
   ActionHash,
   AgentPubKey,
   AgentPubKeyB64,
+  Create,
+  CreateLink,
+  Delete,
+  DeleteLink,
   DnaHash,
   Duration,
   EntryHash,
+  SignedActionHashed,
   Timestamp,
+  Update,
 } from '@holochain/client';
 import { AppletId, WAL } from '@theweave/api';
 
@@ -15,6 +21,14 @@ export interface RelatedGroup {
   group_dna_hash: DnaHash;
 }
 
+export type StewardCapability =
+  | { type: 'ManageApplets' }
+  | { type: 'EditProfile' }
+  | { type: 'ManageStewards' }
+  | { type: 'InviteMembers' }
+  | { type: 'WriteMetaData'; content: string }
+  | { type: 'ManageCustomViews' };
+
 export type StewardPermission = {
   /**
    * ActionHash of the StewardPermission based on which this permission has been issued
@@ -22,6 +36,20 @@ export type StewardPermission = {
   permission_hash?: ActionHash;
   for_agent: AgentPubKey;
   expiry?: number;
+  /**
+   * Capabilities granted by this permission. If undefined, all capabilities are granted.
+   */
+  scope?: StewardCapability[];
+  /**
+   * Approvals of founders for non-expiring permissions in groups with a founder
+   * threshold larger than 1
+   */
+  founder_signatures?: FounderSignature[];
+};
+
+export type FounderSignature = {
+  founder: AgentPubKey;
+  signature: Uint8Array;
 };
 
 export type StewardPermissionClaim = {
@@ -32,6 +60,37 @@ export type StewardPermissionClaim = {
   permission: StewardPermission;
 };
 
+export type StewardPermissionRevocation = {
+  /**
+   * ActionHash of the StewardPermission based on which the revocation is issued
+   */
+  permission_hash?: ActionHash;
+  /**
+   * ActionHash of the StewardPermission that gets revoked
+   */
+  revoked_permission_hash: ActionHash;
+  reason?: string;
+};
+
+/**
+ * Acknowledges the revocation of a StewardPermission, or of one of the permissions it
+ * has been issued under, on the source chain of the revoked agent. Committed
+ * automatically when the agent reads their accountabilities.
+ */
+export type StewardPermissionRevocationReceipt = {
+  revocation_hash: ActionHash;
+  revoked_permission_hash: ActionHash;
+};
+
+/**
+ * References the StewardPermission based on which a steward deletes an entry or link
+ * of another agent. Committed automatically right before the delete.
+ */
+export type StewardDeleteAuthorization = {
+  permission_hash: ActionHash;
+  deleted_action_hash: ActionHash;
+};
+
 export type Accountability =
   | {
       type: 'Progenitor';
@@ -50,8 +109,23 @@ export type GroupProfile = {
    */
   permission_hash?: ActionHash;
   name: string;
+  /**
+   * Inline icon as data URL. Deprecated in favor of icon_hash and must be empty if
+   * icon_hash is set.
+   */
   icon_src: string;
   meta_data?: string;
+  /**
+   * Entry hash of the GroupIcon of the group
+   */
+  icon_hash?: EntryHash;
+};
+
+export type GroupIcon = {
+  /**
+   * base64 encoded data URL of the icon, e.g. "data:image/png;base64,..."
+   */
+  src: string;
 };
 
 export const GROUP_DESCRIPTION_NAME = 'description';
@@ -61,6 +135,50 @@ export type GroupMetaData = {
   permission_hash?: ActionHash;
   name: string;
   data: string;
+  /**
+   * ActionHash of the GroupMetaDataSchema that the data conforms to. Must be the current
+   * schema of the key. If not provided, the current schema is filled in and keys without
+   * a schema get a String schema registered.
+   */
+  schema_hash?: ActionHash;
+  /**
+   * Set if this GroupMetaData re-publishes an earlier revision
+   */
+  revert?: GroupMetaDataRevert;
+};
+
+export type GroupMetaDataRevert = {
+  /**
+   * ActionHash of the GroupMetaData revision that got re-published
+   */
+  revision_hash: ActionHash;
+  reason?: string;
+};
+
+export type RevertGroupMetaDataInput = {
+  permission_hash?: ActionHash;
+  revision_hash: ActionHash;
+  reason?: string;
+};
+
+export type GroupMetaDataValueType =
+  | { type: 'String' }
+  | { type: 'Number' }
+  | { type: 'Boolean' }
+  | { type: 'Json' }
+  | { type: 'JsonObject' }
+  | { type: 'JsonArray' }
+  | { type: 'Enum'; content: string[] }
+  | { type: 'OneOf'; content: GroupMetaDataValueType[] };
+
+/**
+ * Declares the type of values that a GroupMetaData key accepts
+ */
+export type GroupMetaDataSchema = {
+  permission_hash?: ActionHash;
+  name: string;
+  value_type: GroupMetaDataValueType;
+  description?: string;
 };
 
 /**
@@ -99,6 +217,53 @@ export type Applet = {
   meta_data?: string;
 };
 
+export type AppletUpgrade = {
+  /**
+   * ActionHash of the StewardPermission based on which the upgrade has been recorded
+   */
+  permission_hash?: ActionHash;
+  /**
+   * Entry hash of the Applet that gets upgraded
+   */
+  applet_hash: EntryHash;
+  /**
+   * ActionHash of the AppletUpgrade that this upgrade succeeds
+   */
+  previous_upgrade_hash?: ActionHash;
+  sha256_happ: string;
+  sha256_ui: string | undefined;
+  sha256_webhapp: string | undefined;
+  distribution_info: string;
+  /**
+   * Whether the new version can operate on the data of the previous version
+   */
+  compatible: boolean;
+};
+
+export type AppletArchival = {
+  /**
+   * ActionHash of the StewardPermission based on which the applet gets (un)archived
+   */
+  permission_hash?: ActionHash;
+  applet_hash: EntryHash;
+  /**
+   * true if the applet gets archived, false if it gets unarchived
+   */
+  archived: boolean;
+  reason?: string;
+  /**
+   * ActionHash of the latest AppletArchival of the applet known to the author.
+   * Archivals are ordered by these references instead of by their timestamps.
+   */
+  previous_archival?: ActionHash;
+};
+
+export type ArchiveAppletInput = {
+  applet_hash: EntryHash;
+  permission_hash?: ActionHash;
+  reason?: string;
+};
+
 export type AppletEntryPrivate = {
   public_entry_hash: EntryHash;
   applet: Applet;
@@ -110,6 +275,11 @@ export type JoinAppletInput = {
   joining_pubkey: AgentPubKey;
 };
 
+export type DanglingJoinedLink = {
+  create_link_hash: ActionHash;
+  applet_hash: EntryHash;
+};
+
 export type AppletAgent = {
   group_pubkey: AgentPubKey;
   applet_pubkey: AgentPubKey;
@@ -117,13 +287,135 @@ export type AppletAgent = {
 
 export type GroupDnaProperties = {
   progenitor: AgentPubKeyB64 | null;
+  /**
+   * If true, agents other than the progenitor need to provide a GroupMembraneProof
+   * in order to join the group
+   */
+  invite_only?: boolean;
+  /**
+   * Founding council of the group. Founders have the same rights as the progenitor.
+   */
+  founders?: AgentPubKeyB64[];
+  /**
+   * Number of founders that need to approve non-expiring steward permissions
+   */
+  founder_threshold?: number;
 };
 
-export type SignalPayloadGroup = {
-  type: 'Arbitrary';
+export type GroupInvitation = {
   /**
-   * Arbitrary string content but should be parseable to type GroupRemoteSignal
+   * ActionHash of the StewardPermission based on which the invitation has been issued
    */
+  permission_hash?: ActionHash;
+  issuer: AgentPubKey;
+  for_agent: AgentPubKey;
+  expiry?: number;
+  /**
+   * Only a limit of zero can be enforced since the invitation is bound to a single agent
+   */
+  max_uses?: number;
+};
+
+/**
+ * Membrane proof to join an invite-only group. Needs to be msgpack encoded
+ * before being passed as membrane proof at install time.
+ */
+export type GroupMembraneProof = {
+  invitation: GroupInvitation;
+  signature: Uint8Array;
+  /**
+   * ActionHash of the GroupInvitation entry committed by the issuer
+   */
+  invitation_hash: ActionHash;
+  /**
+   * Latest action of the issuer at the time they handed out the membrane proof
+   */
+  issuer_chain_top: ActionHash;
+};
+
+export type CreateGroupInvitationInput = {
+  permission_hash?: ActionHash;
+  for_agent: AgentPubKey;
+  expiry?: number;
+  max_uses?: number;
+};
+
+export type SignalPayloadGroup =
+  | {
+      type: 'Arbitrary';
+      /**
+       * Arbitrary string content but should be parseable to type GroupRemoteSignal
+       */
+      content: Uint8Array;
+    }
+  | ({ type: 'Group' } & GroupSignal)
+  | {
+      type: 'EntryCreated';
+      action: SignedActionHashed<Create>;
+      app_entry: GroupEntryTypes;
+    }
+  | {
+      type: 'EntryUpdated';
+      action: SignedActionHashed<Update>;
+      app_entry: GroupEntryTypes;
+      original_app_entry: GroupEntryTypes;
+    }
+  | {
+      type: 'EntryDeleted';
+      action: SignedActionHashed<Delete>;
+      original_app_entry: GroupEntryTypes;
+    }
+  | {
+      type: 'LinkCreated';
+      action: SignedActionHashed<CreateLink>;
+      link_type: string;
+    }
+  | {
+      type: 'LinkDeleted';
+      action: SignedActionHashed<DeleteLink>;
+      link_type: string;
+    };
+
+export type GroupEntryTypes =
+  | ({ type: 'StewardPermission' } & StewardPermission)
+  | ({ type: 'StewardPermissionClaim' } & StewardPermissionClaim)
+  | ({ type: 'Applet' } & Applet)
+  | ({ type: 'AppletPrivate' } & AppletEntryPrivate)
+  | ({ type: 'AppletClonedCell' } & AppletClonedCell)
+  | ({ type: 'GroupProfile' } & GroupProfile)
+  | ({ type: 'GroupMetaData' } & GroupMetaData)
+  | ({ type: 'StewardPermissionRevocation' } & StewardPermissionRevocation)
+  | ({ type: 'GroupInvitation' } & GroupInvitation)
+  | ({ type: 'AppletUpgrade' } & AppletUpgrade)
+  | ({ type: 'AppletArchival' } & AppletArchival)
+  | ({ type: 'AppletClonedCellArchival' } & AppletClonedCellArchival)
+  | ({ type: 'GroupIcon' } & GroupIcon)
+  | ({ type: 'GroupMetaDataSchema' } & GroupMetaDataSchema)
+  | ({ type: 'StewardPermissionRevocationReceipt' } & StewardPermissionRevocationReceipt)
+  | ({ type: 'StewardDeleteAuthorization' } & StewardDeleteAuthorization)
+  | { type: 'AppletClonedCellPrivate' | 'SharedAppletClonedCell' };
+
+/**
+ * Structured signal sent between members of the group
+ */
+export type GroupSignal = {
+  /**
+   * Random identifier of the signal, e.g. to deduplicate signals
+   */
+  message_id: string;
+  /**
+   * The agent that sent the signal, as verified by the receiving conductor
+   */
+  sender: AgentPubKey;
+  /**
+   * Topic that receivers can filter by
+   */
+  topic: string;
+  /**
+   * The Applet that the signal is scoped to, if any
+   */
+  applet_hash?: EntryHash;
+  timestamp: number;
   content: Uint8Array;
 };
 
@@ -133,14 +425,60 @@ export type SignalPayloadPeerStatus =
       from_agent: AgentPubKey;
       status: string;
       tz_utc_offset: number;
+      presence?: Presence;
     }
   | {
       type: 'Pong';
       from_agent: AgentPubKey;
       status: string;
       tz_utc_offset: number;
+      presence?: Presence;
+      /**
+       * Peers that the sender has recently heard from
+       */
+      digest: PeerLastSeen[];
     };
 
+/**
+ * When an agent has last been heard from by the agent sharing it
+ */
+export type PeerLastSeen = {
+  agent: AgentPubKey;
+  last_seen: Timestamp;
+};
+
+export type Availability =
+  | { type: 'Online' }
+  | { type: 'Away' }
+  | { type: 'DoNotDisturb' }
+  | {
+      type: 'OfflineAt';
+      /**
+       * Time from which on the agent will be offline
+       */
+      content: Timestamp;
+    };
+
+export type CustomStatus = {
+  message: string;
+  /**
+   * Time after which the custom status should not be displayed anymore
+   */
+  expires_at?: Timestamp;
+};
+
+/**
+ * Structured presence of an agent
+ */
+export type Presence = {
+  availability: Availability;
+  custom_status?: CustomStatus;
+  /**
+   * The Applet that the agent currently has focused, only if they choose to share it
+   */
+  focused_applet?: EntryHash;
+};
+
 /**
  * Assets dna
  */
@@ -196,7 +534,31 @@ export type AssetRelationWithTags = {
   dst_wal: WAL;
   tags: string[];
   relation_hash: EntryHash;
+  /**
+   * Timestamp of the first creation of the AssetRelation entry
+   */
   created_at: number;
+  /**
+   * Agents that have asserted the asset relation, earliest first
+   */
+  endorsements: Endorsement[];
+  /**
+   * Agents that have added each of the tags, earliest first
+   */
+  tag_endorsements: TagEndorsements[];
+};
+
+/**
+ * An agent that has asserted an asset relation or added a relationship tag to it
+ */
+export type Endorsement = {
+  author: AgentPubKey;
+  timestamp: number;
+};
+
+export type TagEndorsements = {
+  tag: string;
+  endorsements: Endorsement[];
 };
 
 export type RelateAssetsInput = {
@@ -216,11 +578,75 @@ export type AppletClonedCell = {
   properties?: unknown;
 };
 
+export type AppletClonedCellArchival = {
+  /**
+   * ActionHash of the StewardPermission based on which the cloned cell gets (un)archived
+   */
+  permission_hash?: ActionHash;
+  applet_cloned_cell_hash: EntryHash;
+  /**
+   * true if the cloned cell gets archived, false if it gets unarchived
+   */
+  archived: boolean;
+  reason?: string;
+  /**
+   * ActionHash of the latest AppletClonedCellArchival of the cloned cell known to the
+   * author. Archivals are ordered by these references instead of by their timestamps.
+   */
+  previous_archival?: ActionHash;
+};
+
+export type ArchiveClonedCellInput = {
+  applet_cloned_cell_hash: EntryHash;
+  permission_hash?: ActionHash;
+  reason?: string;
+};
+
+export type ShareClonedCellInput = {
+  applet_cloned_cell: AppletClonedCell;
+  recipients: AgentPubKey[];
+};
+
+export type ClonedCellSharedWithMe = {
+  shared_by: AgentPubKey;
+  /**
+   * ActionHash of the SharedAppletClonedCell entry
+   */
+  shared_cloned_cell_hash: ActionHash;
+  applet_cloned_cell_hash: EntryHash;
+  applet_cloned_cell: AppletClonedCell;
+};
+
 export type TagsToAssetInput = {
   wal: WAL;
   tags: string[];
 };
 
+export type RemoveAssetRelationInput = {
+  relation_hash: EntryHash;
+  reason?: string;
+};
+
+export type AssetRelationTombstone = {
+  relation_hash: EntryHash;
+  reason?: string;
+  delegation_hashes: ActionHash[];
+  /**
+   * StewardPermission based on which the remover removed the relation as a steward.
+   * Tombstones of stewards only hide the relation while this permission is in force.
+   */
+  steward_permission_hash?: ActionHash;
+};
+
+export type DelegateAssetRelationRemovalInput = {
+  delegate: AgentPubKey;
+  /**
+   * Microseconds since the Unix Epoch. Defaults to the maximum duration of a delegation
+   * (90 days) from now.
+   */
+  expiry?: number;
+};
+
 export type RemoveTagsFromAssetRelationInput = {
   relation_hash: EntryHash;
   tags: string[];
@@ -243,3 +669,28 @@ export type GroupRemoteSignal =
       appletId: AppletId;
       payload: Uint8Array;
     };
+
+/**
+ * Pagination
+ */
+
+export type PaginationInput = {
+  /**
+   * The cursor returned with the previous page. undefined to get the first page.
+   */
+  cursor?: PageCursor;
+  limit: number;
+};
+
+export type PageCursor = {
+  bucket: number;
+  last_target: Uint8Array;
+};
+
+export type Page<T> = {
+  items: T[];
+  /**
+   * Cursor to get the next page with. undefined if this is the last page.
+   */
+  next_cursor: PageCursor | undefined;
+};
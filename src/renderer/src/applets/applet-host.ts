@@ -193,7 +193,7 @@ export function buildHeadlessWeaveClient(mossStore: MossStore): WeaveServices {
       addAssetRelation: (_srcWal: WAL, _dstWal: WAL, _tags?: string[]) => {
         throw new Error('removeTagsFromAsset is not supported in headless WeaveServices.');
       },
-      removeAssetRelation: (_relationHash: EntryHash) => {
+      removeAssetRelation: (_relationHash: EntryHash, _reason?: string) => {
         throw new Error('removeAssetRelation is not supported in headless WeaveServices.');
       },
       addTagsToAssetRelation: (_relationHash: EntryHash, _tags: string[]) => {
@@ -872,7 +872,7 @@ export async function handleAppletIframeMessage(
       console.log('### removing asset relation');
       return Promise.all(
         Array.from(groupStores.values()).map((groupStore) =>
-          groupStore!.assetsClient.removeAssetRelation(message.relationHash),
+          groupStore!.assetsClient.removeAssetRelation(message.relationHash, message.reason),
         ),
       );
     }
@@ -1,7 +1,13 @@
-import { AppClient, Record, ActionHash } from '@holochain/client';
+import { AppClient, Record, ActionHash, EntryHash } from '@holochain/client';
 import { EntryRecord, ZomeClient } from '@holochain-open-dev/utils';
+import { Page, PaginationInput } from '@theweave/group-client';
 
-import { CustomView, CustomViewsSignal } from './types.js';
+import {
+  CustomView,
+  CustomViewActiveVersion,
+  CustomViewTarget,
+  CustomViewsSignal,
+} from './types.js';
 
 export class CustomViewsClient extends ZomeClient<CustomViewsSignal> {
   constructor(
@@ -32,8 +38,21 @@ export class CustomViewsClient extends ZomeClient<CustomViewsSignal> {
     return record ? new EntryRecord(record) : undefined;
   }
 
-  deleteCustomView(originalCustomViewHash: ActionHash): Promise<ActionHash> {
-    return this.callZome('delete_custom_view', originalCustomViewHash);
+  /**
+   *
+   * @param originalCustomViewHash
+   * @param permissionHash ActionHash of the StewardPermission of the calling agent if
+   * they are not the author of the custom view
+   * @returns
+   */
+  deleteCustomView(
+    originalCustomViewHash: ActionHash,
+    permissionHash?: ActionHash,
+  ): Promise<ActionHash> {
+    return this.callZome('delete_custom_view', {
+      original_custom_view_hash: originalCustomViewHash,
+      permission_hash: permissionHash,
+    });
   }
 
   async updateCustomView(
@@ -47,10 +66,124 @@ export class CustomViewsClient extends ZomeClient<CustomViewsSignal> {
     return new EntryRecord(record);
   }
 
+  /**
+   * Gets all revisions of a custom view, from the original to the latest one
+   *
+   * @param originalCustomViewHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getCustomViewHistory(
+    originalCustomViewHash: ActionHash,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<CustomView>>> {
+    const records: Record[] = await this.callZome('get_custom_view_history', {
+      input: originalCustomViewHash,
+      local,
+    });
+    return records.map((r) => new EntryRecord(r));
+  }
+
+  /**
+   * Pins the revision of a custom view that members render. Passing no versionHash
+   * unpins the custom view so that its latest revision gets rendered again.
+   */
+  async setCustomViewActiveVersion(
+    customViewHash: ActionHash,
+    versionHash: ActionHash | undefined,
+    permissionHash?: ActionHash,
+  ): Promise<EntryRecord<CustomViewActiveVersion>> {
+    const record: Record = await this.callZome('set_custom_view_active_version', {
+      permission_hash: permissionHash,
+      custom_view_hash: customViewHash,
+      version_hash: versionHash,
+    });
+    return new EntryRecord(record);
+  }
+
+  /**
+   *
+   * @param originalCustomViewHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getCustomViewActiveVersion(
+    originalCustomViewHash: ActionHash,
+    local: boolean = true,
+  ): Promise<CustomViewActiveVersion | undefined> {
+    return this.callZome('get_custom_view_active_version', {
+      input: originalCustomViewHash,
+      local,
+    });
+  }
+
+  /** Custom View Targets */
+
+  /**
+   *
+   * @param target
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getCustomViewsForTarget(
+    target: CustomViewTarget,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<CustomView>>> {
+    const records: Record[] = await this.callZome('get_custom_views_for_target', {
+      input: target,
+      local,
+    });
+    return records.map((r) => new EntryRecord(r));
+  }
+
+  /**
+   *
+   * @param appletHash
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getCustomViewsForApplet(
+    appletHash: EntryHash,
+    local: boolean = true,
+  ): Promise<Array<EntryRecord<CustomView>>> {
+    const records: Record[] = await this.callZome('get_custom_views_for_applet', {
+      input: appletHash,
+      local,
+    });
+    return records.map((r) => new EntryRecord(r));
+  }
+
+  /**
+   *
+   * @param local Whether to use GetStrategy::Local or not
+   * @returns
+   */
+  async getCustomViewsForGroupHome(local: boolean = true): Promise<Array<EntryRecord<CustomView>>> {
+    const records: Record[] = await this.callZome('get_custom_views_for_group_home', {
+      input: null,
+      local,
+    });
+    return records.map((r) => new EntryRecord(r));
+  }
+
   /** All Custom Views */
 
   async getAllCustomViews(local: boolean = true): Promise<Array<EntryRecord<CustomView>>> {
     const records: Record[] = await this.callZome('get_all_custom_views', { input: null, local });
     return records.map((r) => new EntryRecord(r));
   }
+
+  async getAllCustomViewsPage(
+    pagination: PaginationInput,
+    local: boolean = true,
+  ): Promise<Page<EntryRecord<CustomView>>> {
+    const page: Page<Record> = await this.callZome('get_all_custom_views_page', {
+      input: pagination,
+      local,
+    });
+    return {
+      items: page.items.map((r) => new EntryRecord(r)),
+      next_cursor: page.next_cursor,
+    };
+  }
 }
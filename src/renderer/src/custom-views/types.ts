@@ -5,6 +5,8 @@ import {
   Delete,
   CreateLink,
   DeleteLink,
+  ActionHash,
+  EntryHash,
 } from '@holochain/client';
 
 export type CustomViewsSignal =
@@ -35,7 +37,9 @@ export type CustomViewsSignal =
       link_type: string;
     };
 
-export type EntryTypes = { type: 'CustomView' } & CustomView;
+export type EntryTypes =
+  | ({ type: 'CustomView' } & CustomView)
+  | ({ type: 'CustomViewActiveVersion' } & CustomViewActiveVersion);
 
 export interface CustomView {
   name: string;
@@ -47,4 +51,28 @@ export interface CustomView {
   js: string;
 
   css: string;
+
+  permission_hash?: ActionHash;
+
+  /**
+   * Where the custom view gets rendered. Custom views without a target are only
+   * rendered when explicitly opened.
+   */
+  target?: CustomViewTarget;
+}
+
+export type CustomViewTarget =
+  | { type: 'GroupHome' }
+  | { type: 'Applet'; content: EntryHash }
+  | { type: 'AssetType'; content: { applet_hash: EntryHash; name: string } };
+
+export interface CustomViewActiveVersion {
+  permission_hash: ActionHash | undefined;
+
+  custom_view_hash: ActionHash;
+
+  /**
+   * The pinned revision of the custom view. undefined if the custom view is unpinned.
+   */
+  version_hash: ActionHash | undefined;
 }
@@ -143,7 +143,13 @@ export class DangerTone extends LitElement {
       // 1. Get current group profile and description
       const groupProfileRecord = await this._groupStore.groupClient.getGroupProfile(false);
       if (!groupProfileRecord) throw new Error('Could not read group profile.');
-      const groupProfile = { ...groupProfileRecord.entry, name: newName };
+      const groupProfile = {
+        ...(await this._groupStore.groupClient.resolveGroupProfileIcon(
+          groupProfileRecord.entry,
+          false,
+        )),
+        name: newName,
+      };
       const groupDescriptionRecord = await this._groupStore.groupClient.getGroupDescription(false);
       const groupDescription = groupDescriptionRecord?.entry.data;
 
@@ -5,7 +5,8 @@ import { msg } from '@lit/localize';
 
 export async function deprecateTool(groupStore: GroupStore, appletHash: EntryHash) {
   try {
-    await groupStore.groupClient.archiveApplet(appletHash);
+    const permissionHash = await groupStore.myStewardPermissionHash();
+    await groupStore.groupClient.archiveApplet(appletHash, permissionHash);
     await groupStore.allAdvertisedApplets.reload();
     notify(msg('Tool deprecated.'));
   } catch (e) {
@@ -16,7 +17,8 @@ export async function deprecateTool(groupStore: GroupStore, appletHash: EntryHas
 
 export async function undeprecateTool(groupStore: GroupStore, appletHash: EntryHash) {
   try {
-    await groupStore.groupClient.unarchiveApplet(appletHash);
+    const permissionHash = await groupStore.myStewardPermissionHash();
+    await groupStore.groupClient.unarchiveApplet(appletHash, permissionHash);
     await groupStore.allAdvertisedApplets.reload();
     notify(msg('Tool undeprecated.'));
   } catch (e) {
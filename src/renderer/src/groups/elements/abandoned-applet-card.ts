@@ -138,7 +138,8 @@ export class AbandonedAppletCard extends LitElement {
 
   async archiveApplet() {
     try {
-      await this.groupStore.groupClient.archiveApplet(this.appletHash);
+      const permissionHash = await this.groupStore.myStewardPermissionHash();
+      await this.groupStore.groupClient.archiveApplet(this.appletHash, permissionHash);
       await this.groupStore.allAdvertisedApplets.reload();
       notify(msg('Tool archived.'));
     } catch (e) {
@@ -149,7 +150,8 @@ export class AbandonedAppletCard extends LitElement {
 
   async unArchiveApplet() {
     try {
-      await this.groupStore.groupClient.unarchiveApplet(this.appletHash);
+      const permissionHash = await this.groupStore.myStewardPermissionHash();
+      await this.groupStore.groupClient.unarchiveApplet(this.appletHash, permissionHash);
       await this.groupStore.allAdvertisedApplets.reload();
       notify(msg('Tool undeprecated.'));
     } catch (e) {
@@ -228,7 +228,8 @@ export class AppletDetailCard extends LitElement {
 
   async archiveApplet() {
     try {
-      await this.groupStore.groupClient.archiveApplet(this.appletHash);
+      const permissionHash = await this.groupStore.myStewardPermissionHash();
+      await this.groupStore.groupClient.archiveApplet(this.appletHash, permissionHash);
       await this.groupStore.allAdvertisedApplets.reload();
       notify(msg('Tool archived.'));
     } catch (e) {
@@ -239,7 +240,8 @@ export class AppletDetailCard extends LitElement {
 
   async unArchiveApplet() {
     try {
-      await this.groupStore.groupClient.unarchiveApplet(this.appletHash);
+      const permissionHash = await this.groupStore.myStewardPermissionHash();
+      await this.groupStore.groupClient.unarchiveApplet(this.appletHash, permissionHash);
       await this.groupStore.allAdvertisedApplets.reload();
       notify(msg('Tool unarchived.'));
     } catch (e) {
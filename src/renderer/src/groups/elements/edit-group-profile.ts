@@ -49,9 +49,13 @@ export class EditGroupProfile extends LitElement {
     }
     try {
       this.committing = true;
+      const iconHash = await this._groupStore.groupClient.createGroupIcon({
+        src: fields.icon_src,
+      });
       await this._groupStore.groupClient.setGroupProfile({
         name: fields.name,
-        icon_src: fields.icon_src,
+        icon_src: '',
+        icon_hash: iconHash,
       });
     } catch (e) {
       this.committing = false;
@@ -66,6 +66,7 @@ import {
   AssetRelationWithTags,
   decodeAssetRelationWALs,
   GroupRemoteSignal,
+  PeerLastSeen,
   PeerStatusClient,
   SignalPayloadAssets,
   SignalPayloadPeerStatus,
@@ -94,6 +95,7 @@ import { FoyerNotificationSettings, DEFAULT_FOYER_NOTIFICATION_SETTINGS } from '
 
 export const NEW_APPLETS_POLLING_FREQUENCY = 10000;
 const PING_AGENTS_FREQUENCY_MS = 8000;
+const PING_FANOUT = 8; // Number of known agents pinged per round, the others are learned about via the presence digests in the pongs
 const GET_AGENT_INFO_FREQUENCY_MS = 10000; // Poll agentInfo to discover agents in network
 export const OFFLINE_THRESHOLD = 26000; // Peer is considered offline if they did not respond to 3 consecutive pings
 export const IDLE_THRESHOLD = 300000; // Peer is considered inactive after 5 minutes without interaction inside Moss
@@ -130,6 +132,9 @@ export class GroupStore {
 
   private _knownAgents: Writable<Set<AgentPubKeyB64>> = writable(new Set());
 
+  // Latest presence digest received in a pong per agent since the last ping round
+  private _receivedDigests: Record<AgentPubKeyB64, PeerLastSeen[]> = {};
+
   private _ignoredApplets: Writable<AppletId[]> = writable([]);
 
   private _hiddenAgents: Writable<AgentPubKeyB64[]> = writable([]);
@@ -236,6 +241,7 @@ export class GroupStore {
         onlineDebugLog(`[OnlineDebug][${groupIdShort}] Pong from ${agentB64.slice(0, 8)}: ${prev ?? 'unknown'} -> ${signal.status} (instance=${this._instanceId})`);
         _prevAgentStatus[agentB64] = signal.status;
         this.updatePeerStatus(signal.from_agent, signal.status, signal.tz_utc_offset);
+        this._receivedDigests[agentB64] = signal.digest ?? [];
       }
       if (signal.type == 'Ping') {
         const prev = _prevAgentStatus[agentB64];
@@ -245,7 +251,13 @@ export class GroupStore {
         const status =
           now - this.mossStore.myLatestActivity > IDLE_THRESHOLD ? 'inactive' : 'online';
         this.updatePeerStatus(signal.from_agent, signal.status, signal.tz_utc_offset);
-        await this.peerStatusClient.pong([signal.from_agent], status, this.mossStore.tzUtcOffset());
+        await this.peerStatusClient.pong(
+          [signal.from_agent],
+          status,
+          this.mossStore.tzUtcOffset(),
+          undefined,
+          this.presenceDigest(),
+        );
       }
     });
 
@@ -864,6 +876,18 @@ export class GroupStore {
 
   myAccountabilities = lazyReloadableStore(async () => this.groupClient.getMyAccountabilities());
 
+  /**
+   * ActionHash of my StewardPermission, if I have one, to be passed along with
+   * actions that require steward privileges
+   */
+  async myStewardPermissionHash(): Promise<ActionHash | undefined> {
+    const accountabilities = await this.groupClient.getMyAccountabilities();
+    for (const acc of accountabilities) {
+      if (acc.type === 'Steward') return acc.content.permission_hash;
+    }
+    return undefined;
+  }
+
   allAgentsAccountabilities = lazyReloadableStore(async () =>
     this.groupClient.getAllAgentsAccountabilities(),
   );
@@ -876,7 +900,7 @@ export class GroupStore {
     async () => {
       // only poll in case groupProfile is not yet defined
       const entryRecord = await this.groupClient.getGroupProfile(true);
-      return entryRecord?.entry;
+      return entryRecord ? this.groupClient.resolveGroupProfileIcon(entryRecord.entry) : undefined;
     },
     undefined,
     3000,
@@ -884,7 +908,7 @@ export class GroupStore {
     async () => {
       // only poll in case groupProfile is not yet defined
       const entryRecord = await this.groupClient.getGroupProfile(true);
-      return entryRecord?.entry;
+      return entryRecord ? this.groupClient.resolveGroupProfileIcon(entryRecord.entry) : undefined;
     },
   );
 
@@ -1211,7 +1235,50 @@ export class GroupStore {
     });
   }
 
+  /**
+   * The peers that this agent has recently heard from, to be shared in pongs
+   */
+  presenceDigest(): PeerLastSeen[] {
+    const peerStatuses = get(this._peerStatuses) ?? {};
+    return Object.entries(peerStatuses)
+      .filter(([_, status]) => status.status !== 'offline')
+      .map(([agentB64, status]) => ({
+        agent: decodeHashFromBase64(agentB64),
+        last_seen: status.lastSeen * 1000,
+      }));
+  }
+
+  /**
+   * Merges the presence digests received in pongs since the last ping round into the
+   * peer statuses so that agents that have not been pinged directly show up as well
+   */
+  async applyPresenceSnapshot(): Promise<void> {
+    const digests = Object.values(this._receivedDigests);
+    this._receivedDigests = {};
+    if (digests.length === 0) return;
+    const snapshot = await this.peerStatusClient.getPresenceSnapshot(digests);
+    const now = Date.now();
+    this._peerStatuses.update((statuses) => {
+      // Create a new object to ensure derived stores detect the change
+      const newStatuses = statuses ? { ...statuses } : {};
+      snapshot.forEach((peer) => {
+        const agentB64 = encodeHashToBase64(peer.agent);
+        const lastSeen = Math.floor(peer.last_seen / 1000);
+        if (this.isAgentHidden(agentB64) || now - lastSeen > OFFLINE_THRESHOLD) return;
+        const previous = newStatuses[agentB64];
+        if (previous && previous.lastSeen >= lastSeen) return;
+        newStatuses[agentB64] = {
+          lastSeen,
+          status: previous && previous.status !== 'offline' ? previous.status : 'online',
+          tzUtcOffset: previous?.tzUtcOffset,
+        };
+      });
+      return newStatuses;
+    });
+  }
+
   async pingAgentsAndCleanPeerStatuses() {
+    await this.applyPresenceSnapshot();
     const now = Date.now();
     let markedOfflineCount = 0;
     // Set unresponsive agents to offline
@@ -1239,10 +1306,10 @@ export class GroupStore {
   }
 
   /**
-   * Pings all agents discovered via agentInfo.
+   * Pings up to PING_FANOUT randomly chosen agents discovered via agentInfo.
    * Self is already excluded from the knownAgents set by pollAgentInfo().
-   * Since agentInfo only returns agents in the network, we ping all of them
-   * to determine their actual online/offline status.
+   * The status of the agents that are not pinged is learned about via the
+   * presence digests in the pongs, see applyPresenceSnapshot().
    */
   async pingAgents(): Promise<void> {
     const now = Date.now();
@@ -1256,7 +1323,7 @@ export class GroupStore {
     const knownAgents = knownAgentsB64.map((b64) => decodeHashFromBase64(b64));
 
     return knownAgents.length > 0
-      ? this.peerStatusClient.ping(knownAgents, myStatus, tzOffset)
+      ? this.peerStatusClient.ping(knownAgents, myStatus, tzOffset, undefined, PING_FANOUT)
       : Promise.resolve();
   }
 
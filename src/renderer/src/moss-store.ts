@@ -969,21 +969,26 @@ export class MossStore {
 
     const groupStore = await this.groupStore(groupDnaHash);
 
-    const groupProfile: GroupProfile = {
-      icon_src: logo,
-      name,
+    // Note that the person that creates the group is always the progenitor so they
+    // don't need to provide a permission_hash in the GroupProfile
+    const setGroupProfile = async (groupStore: GroupStore) => {
+      const iconHash = await groupStore.groupClient.createGroupIcon({ src: logo });
+      const groupProfile: GroupProfile = {
+        icon_src: '',
+        icon_hash: iconHash,
+        name,
+      };
+      await groupStore.groupClient.setGroupProfile(groupProfile);
     };
 
     try {
       if (!groupStore) throw new Error('GroupStore still undefined after joining group.');
-      // Note that the person that creates the group is always the progenitor so they
-      // don't need to provide a permission_hash in the GroupProfile
-      await groupStore.groupClient.setGroupProfile(groupProfile);
+      await setGroupProfile(groupStore);
     } catch (e) {
       if ((e as any).toString().includes('source chain head has moved')) {
         console.log('Source chain head has moved error, retrying to create profile...');
         try {
-          await groupStore!.groupClient.setGroupProfile(groupProfile);
+          await setGroupProfile(groupStore!);
         } catch (e) {
           try {
             await this.leaveGroup(groupDnaHash);
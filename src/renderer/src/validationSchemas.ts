@@ -475,6 +475,7 @@ export const AppletToParentRequest = Type.Union([
     {
       type: Type.Literal('remove-asset-relation'),
       relationHash: EntryHash,
+      reason: Type.Optional(Type.String()),
     },
     { additionalProperties: false },
   ),
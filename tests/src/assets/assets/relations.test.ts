@@ -1,13 +1,26 @@
 import { assert, test } from 'vitest';
+import { fail } from 'assert';
 import { runScenario, dhtSync } from '@holochain/tryorama';
-import { AppBundleSource, encodeHashToBase64, EntryHash, fakeActionHash } from '@holochain/client';
+import {
+  AgentPubKey,
+  AppBundleSource,
+  encodeHashToBase64,
+  EntryHash,
+  fakeActionHash,
+  Record as HolochainRecord,
+} from '@holochain/client';
+import { EntryRecord } from '@holochain-open-dev/utils';
 import { WAL } from '@theweave/api';
 
 import { getCellByRoleName, GROUP_HAPP_PATH } from '../../shared.js';
+import { nAgentsOneProgenitor } from '../../group/group/common.js';
 import {
   AssetRelation,
   AssetRelationAndHash,
+  AssetRelationTombstone,
   AssetRelationWithTags,
+  Page,
+  PageCursor,
   RelateAssetsInput,
   RemoveTagsFromAssetRelationInput,
 } from '@theweave/group-client';
@@ -106,7 +119,7 @@ test('Add an asset relation, remove it again and try to get it from the ALL_ASSE
     await assetsCellBob.callZome({
       zome_name: 'assets',
       fn_name: 'remove_asset_relation',
-      payload: { input: assetRelation1.relation_hash },
+      payload: { input: { relation_hash: assetRelation1.relation_hash } },
     });
 
     await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
@@ -399,12 +412,12 @@ test('Add an asset relation between 2 WALs, then read and modify it', async () =
     assert.deepEqual(input1.dst_wal.hrl, rel.dst_wal.hrl);
     assert.deepEqual(Uint8Array.from(input1.dst_wal.context), Uint8Array.from(rel.dst_wal.context));
 
-    // Bob removes some tags and Alice should not see these tags anymore
+    // Alice removes some of her tags and these should not show up anymore
     const input: RemoveTagsFromAssetRelationInput = {
       relation_hash: rel.relation_hash,
       tags: ['depends_on', 'loves'],
     };
-    await assetsCellBob.callZome({
+    await assetsCellAlice.callZome({
       zome_name: 'assets',
       fn_name: 'remove_tags_from_asset_relation',
       payload: { input },
@@ -491,7 +504,7 @@ test('Add an asset relation between 2 WALs, then read and modify it', async () =
     await assetsCellAlice.callZome({
       zome_name: 'assets',
       fn_name: 'remove_asset_relation',
-      payload: { input: assetRelation1.relation_hash },
+      payload: { input: { relation_hash: assetRelation1.relation_hash } },
     });
 
     await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
@@ -643,6 +656,8 @@ test('Add two asset relations with tags and read them with get_all_asset_relatio
       tags: ['depends_on', 'loves', 'cares_about'],
       relation_hash: assetRelation1.relation_hash,
       created_at: assetRelation1.created_at,
+      endorsements: assetRelation1.endorsements,
+      tag_endorsements: assetRelation1.tag_endorsements,
     });
 
     const wal2RelationReadByAlice = allAssetRelationsWithTags.find(
@@ -656,6 +671,246 @@ test('Add two asset relations with tags and read them with get_all_asset_relatio
       tags: ['refers_to'],
       relation_hash: assetRelation2.relation_hash,
       created_at: assetRelation2.created_at,
+      endorsements: assetRelation2.endorsements,
+      tag_endorsements: assetRelation2.tag_endorsements,
+    });
+  });
+});
+
+test('Page through all asset relations', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [alice] = await scenario.addPlayersWithApps([{ appBundleSource }]);
+
+    const assetsCellAlice = getCellByRoleName(alice, 'assets');
+
+    // 1. Alice adds 5 asset relations
+    const relationHashes: string[] = [];
+    for (let i = 0; i < 5; i++) {
+      const relation: AssetRelationWithTags = await assetsCellAlice.callZome({
+        zome_name: 'assets',
+        fn_name: 'add_asset_relation',
+        payload: {
+          input: {
+            src_wal: { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] },
+            dst_wal: { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] },
+            tags: [],
+          },
+        },
+      });
+      relationHashes.push(encodeHashToBase64(relation.relation_hash));
+    }
+
+    // 2. Alice reads them in pages of 2
+    const pagedHashes: string[] = [];
+    let cursor: PageCursor | undefined = undefined;
+    let pages = 0;
+    do {
+      const page: Page<AssetRelationAndHash> = await assetsCellAlice.callZome({
+        zome_name: 'assets',
+        fn_name: 'get_all_asset_relations_page',
+        payload: { input: { cursor, limit: 2 } },
+      });
+      assert(page.items.length <= 2);
+      page.items.forEach((r) => pagedHashes.push(encodeHashToBase64(r.relation_hash)));
+      cursor = page.next_cursor;
+      pages += 1;
+    } while (cursor && pages < 10);
+
+    assert.equal(pagedHashes.length, 5);
+    assert.deepEqual(pagedHashes.sort(), relationHashes.sort());
+
+    // 3. The unpaginated call keeps returning all of them
+    const allAssetRelations: AssetRelationAndHash[] = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_all_asset_relations',
+      payload: { input: null },
+    });
+    assert.equal(allAssetRelations.length, 5);
+  });
+});
+
+test('Only the creator of an asset relation and agents delegated by them can remove it', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    // Alice is the progenitor, Bob is a regular member
+    const [[alice, alicePubKey], [bob, bobPubKey]] = await nAgentsOneProgenitor(
+      scenario,
+      appBundleSource,
+      ['group'],
+      2,
+    );
+    await scenario.shareAllAgents();
+
+    const assetsCellAlice = getCellByRoleName(alice, 'assets');
+    const assetsCellBob = getCellByRoleName(bob, 'assets');
+
+    // 1. Alice adds an asset relation
+    const relation: AssetRelationWithTags = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'add_asset_relation',
+      payload: {
+        input: {
+          src_wal: { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] },
+          dst_wal: { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] },
+          tags: ['depends_on'],
+        },
+      },
+    });
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    // 2. Bob tries to remove it but cannot delete Alice's links
+    await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'remove_asset_relation',
+      payload: { input: { relation_hash: relation.relation_hash, reason: 'spam' } },
+    });
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    let allAssetRelationsAlice: AssetRelationWithTags[] = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_all_asset_relations_with_tags',
+      payload: { input: null },
+    });
+    assert.equal(allAssetRelationsAlice.length, 1);
+    assert.deepEqual(allAssetRelationsAlice[0].tags, ['depends_on']);
+
+    // 3. Delegations cannot last longer than the maximum delegation duration
+    try {
+      await assetsCellAlice.callZome({
+        zome_name: 'assets',
+        fn_name: 'delegate_asset_relation_removal',
+        payload: { delegate: bobPubKey, expiry: (Date.now() + 365 * 24 * 60 * 60 * 1000) * 1000 },
+      });
+      fail('Delegations beyond the maximum delegation duration should be rejected.');
+    } catch (e) {
+      if (!e.toString().includes('maximum delegation duration')) {
+        fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // 4. Alice delegates the removal of her asset relations to Bob, now Bob can remove it
+    await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'delegate_asset_relation_removal',
+      payload: { delegate: bobPubKey },
+    });
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    const delegates: AgentPubKey[] = await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_asset_relation_removal_delegates',
+      payload: { input: alicePubKey },
+    });
+    assert.deepEqual(delegates, [bobPubKey]);
+
+    await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'remove_asset_relation',
+      payload: { input: { relation_hash: relation.relation_hash, reason: 'outdated' } },
+    });
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    allAssetRelationsAlice = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_all_asset_relations_with_tags',
+      payload: { input: null },
+    });
+    assert.equal(allAssetRelationsAlice.length, 0);
+
+    // 5. Both removals have been recorded
+    const tombstones: HolochainRecord[] = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_asset_relation_tombstones',
+      payload: { input: relation.relation_hash },
+    });
+    const entryRecords = tombstones.map((r) => new EntryRecord<AssetRelationTombstone>(r));
+    assert.deepEqual(entryRecords.map((r) => r.entry.reason), ['spam', 'outdated']);
+    entryRecords.forEach((r) => assert.deepEqual(r.action.author, bobPubKey));
+    assert.equal(entryRecords[1].entry.delegation_hashes.length, 1);
+  });
+});
+
+test('Every agent asserting an asset relation or adding a tag gets recorded as endorser', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([
+      { appBundleSource },
+      { appBundleSource },
+    ]);
+    await scenario.shareAllAgents();
+
+    const assetsCellAlice = getCellByRoleName(alice, 'assets');
+    const assetsCellBob = getCellByRoleName(bob, 'assets');
+
+    const wal1: WAL = { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] };
+    const wal2: WAL = { hrl: [assetsCellAlice.cell_id[0], await fakeActionHash()] };
+
+    // 1. Alice and then Bob assert the same asset relation with partly the same tags
+    const relationAlice: AssetRelationWithTags = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'add_asset_relation',
+      payload: { input: { src_wal: wal1, dst_wal: wal2, tags: ['loves', 'likes'] } },
+    });
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    const relationBob: AssetRelationWithTags = await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'add_asset_relation',
+      payload: { input: { src_wal: wal1, dst_wal: wal2, tags: ['likes'] } },
+    });
+    assert.deepEqual(relationBob.relation_hash, relationAlice.relation_hash);
+    await dhtSync([alice, bob], assetsCellAlice.cell_id[0]);
+
+    // 2. Both are listed as endorsers of the relation, in the order they asserted it
+    const relation: AssetRelationWithTags | undefined = await assetsCellAlice.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_asset_relation_with_tags',
+      payload: { input: relationAlice.relation_hash },
+    });
+    assert(!!relation);
+    assert.deepEqual(
+      relation.endorsements.map((e) => e.author),
+      [alice.agentPubKey, bob.agentPubKey],
+    );
+    assert(relation.endorsements[0].timestamp <= relation.endorsements[1].timestamp);
+
+    // 3. Tags are attributed to the agents that added them
+    const endorsersOfTag = (tag: string) =>
+      relation.tag_endorsements.find((t) => t.tag === tag)?.endorsements.map((e) => e.author);
+    assert.deepEqual(endorsersOfTag('loves'), [alice.agentPubKey]);
+    assert.deepEqual(endorsersOfTag('likes'), [alice.agentPubKey, bob.agentPubKey]);
+    // Tags added by several agents are listed once
+    assert.deepEqual([...relation.tags].sort(), ['likes', 'loves']);
+
+    // 4. Endorsements are also returned when reading the relations of a WAL
+    const outgoing: AssetRelationWithTags[] = await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_outgoing_asset_relations_with_tags',
+      payload: { input: wal1 },
+    });
+    assert.equal(outgoing.length, 1);
+    assert.deepEqual(outgoing[0].endorsements, relation.endorsements);
+
+    const allRelations: AssetRelationWithTags[] = await assetsCellBob.callZome({
+      zome_name: 'assets',
+      fn_name: 'get_all_asset_relations_with_tags',
+      payload: { input: null },
     });
+    assert.equal(allRelations.length, 1);
+    assert.deepEqual(allRelations[0].endorsements, relation.endorsements);
+    assert.deepEqual([...allRelations[0].tags].sort(), ['likes', 'loves']);
   });
 });
@@ -0,0 +1,128 @@
+import { test } from 'vitest';
+
+import { runScenario, dhtSync } from '@holochain/tryorama';
+import { AppBundleSource, Record as HolochainRecord } from '@holochain/client';
+import { fail } from 'assert';
+
+import { getCellByRoleName, GROUP_HAPP_PATH } from '../../shared.js';
+import { threeAgentsOneProgenitorOneStewardOneMember } from '../group/common.js';
+
+function customView(name: string) {
+  return {
+    name,
+    logo: 'base64pngetc',
+    html: '<div>Hello</div>',
+    js: '',
+    css: '',
+    permission_hash: null,
+    target: null,
+  };
+}
+
+test('Only the author of a custom view or a steward can update or delete it', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [[alice], [bob, _bobPubKey, bobPermissionHash], [charlie]] =
+      await threeAgentsOneProgenitorOneStewardOneMember(scenario, appBundleSource, ['group']);
+
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+    const groupCellBob = getCellByRoleName(bob, 'group');
+    const groupCellCharlie = getCellByRoleName(charlie, 'group');
+
+    // Bob creates a custom view and Charlie tries to update it
+    const bobsView: HolochainRecord = await groupCellBob.callZome({
+      zome_name: 'custom_views',
+      fn_name: 'create_custom_view',
+      payload: customView("Bob's view"),
+    });
+    await dhtSync([alice, bob, charlie], groupCellAlice.cell_id[0]);
+    try {
+      await groupCellCharlie.callZome({
+        zome_name: 'custom_views',
+        fn_name: 'update_custom_view',
+        payload: {
+          previous_custom_view_hash: bobsView.signed_action.hashed.hash,
+          updated_custom_view: customView("Charlie's view now"),
+        },
+      });
+      fail('Charlie should not be allowed to update a custom view of Bob.');
+    } catch (e) {
+      if (!e.toString().includes('Only the author of a custom view or a steward can update it')) {
+        fail(`Got unexpected error when Charlie tried to update a custom view of Bob: ${e}`);
+      }
+    }
+
+    // Charlie creates a custom view that Bob updates as a steward
+    const charliesView: HolochainRecord = await groupCellCharlie.callZome({
+      zome_name: 'custom_views',
+      fn_name: 'create_custom_view',
+      payload: customView("Charlie's view"),
+    });
+    await dhtSync([alice, bob, charlie], groupCellAlice.cell_id[0]);
+    const bobsRevision: HolochainRecord = await groupCellBob.callZome({
+      zome_name: 'custom_views',
+      fn_name: 'update_custom_view',
+      payload: {
+        previous_custom_view_hash: charliesView.signed_action.hashed.hash,
+        updated_custom_view: {
+          ...customView("Charlie's view, approved"),
+          permission_hash: bobPermissionHash,
+        },
+      },
+    });
+    await dhtSync([alice, bob, charlie], groupCellAlice.cell_id[0]);
+
+    // Charlie can still update the revision of Bob since Charlie created the custom view
+    await groupCellCharlie.callZome({
+      zome_name: 'custom_views',
+      fn_name: 'update_custom_view',
+      payload: {
+        previous_custom_view_hash: bobsRevision.signed_action.hashed.hash,
+        updated_custom_view: customView("Charlie's view, revised"),
+      },
+    });
+
+    // Bob deletes the custom view of Charlie as a steward
+    await groupCellBob.callZome({
+      zome_name: 'custom_views',
+      fn_name: 'delete_custom_view',
+      payload: {
+        original_custom_view_hash: charliesView.signed_action.hashed.hash,
+        permission_hash: bobPermissionHash,
+      },
+    });
+  });
+});
+
+test('Custom views cannot exceed their size limits', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [_alice, _bob, [charlie]] = await threeAgentsOneProgenitorOneStewardOneMember(
+      scenario,
+      appBundleSource,
+      ['group'],
+    );
+    const groupCellCharlie = getCellByRoleName(charlie, 'group');
+
+    try {
+      await groupCellCharlie.callZome({
+        zome_name: 'custom_views',
+        fn_name: 'create_custom_view',
+        payload: { ...customView('Too large'), html: 'a'.repeat(1_000_001) },
+      });
+      fail('Custom views with an html larger than the size limit should not be valid.');
+    } catch (e) {
+      if (!e.toString().includes('The html of a custom view must not be larger than')) {
+        fail(`Got unexpected error when creating an oversized custom view: ${e}`);
+      }
+    }
+  });
+});
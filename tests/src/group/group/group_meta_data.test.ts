@@ -0,0 +1,199 @@
+import { assert, test } from 'vitest';
+
+import { runScenario } from '@holochain/tryorama';
+import { AppBundleSource, Record as HolochainRecord, Update } from '@holochain/client';
+import { EntryRecord } from '@holochain-open-dev/utils';
+
+import { getCellByRoleName, GROUP_HAPP_PATH } from '../../shared.js';
+import { installAppWithProgenitor } from './common.js';
+import { GroupMetaData, GroupMetaDataSchema } from '@theweave/group-client';
+import { fail } from 'assert';
+
+test('GroupMetaData is validated against the schema of its key and keys can be listed', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [alice, _alicePubKey] = await installAppWithProgenitor(
+      scenario,
+      appBundleSource,
+      ['group'],
+      true,
+    );
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+
+    // 1. Alice registers a schema for the 'max_members' key
+    const schema: GroupMetaDataSchema = {
+      name: 'max_members',
+      value_type: { type: 'Number' },
+      description: 'Maximum number of members of the group',
+    };
+    const schemaRecord: HolochainRecord = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data_schema',
+      payload: schema,
+    });
+
+    // 2. Data that does not conform to the schema gets rejected
+    const invalid: GroupMetaData = {
+      name: 'max_members',
+      data: 'many',
+    };
+    try {
+      await groupCellAlice.callZome({
+        zome_name: 'group',
+        fn_name: 'set_group_meta_data',
+        payload: invalid,
+      });
+      fail('Setting GroupMetaData that does not conform to the schema should fail.');
+    } catch (e) {
+      if (!e.toString().includes('does not conform to the schema')) {
+        fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // 3. Data that conforms to the schema is accepted
+    const valid: GroupMetaData = {
+      name: 'max_members',
+      data: '42',
+    };
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data',
+      payload: valid,
+    });
+    const record: HolochainRecord | undefined = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_meta_data',
+      payload: { input: 'max_members' },
+    });
+    assert(!!record);
+    assert.equal(new EntryRecord<GroupMetaData>(record).entry.data, '42');
+
+    // 4. After the schema got updated, data referencing the outdated schema gets rejected
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data_schema',
+      payload: { ...schema, description: 'Maximum number of members, including stewards' },
+    });
+    try {
+      await groupCellAlice.callZome({
+        zome_name: 'group',
+        fn_name: 'set_group_meta_data',
+        payload: { ...valid, schema_hash: schemaRecord.signed_action.hashed.hash },
+      });
+      fail('Setting GroupMetaData that references an outdated schema should fail.');
+    } catch (e) {
+      if (!e.toString().includes('current GroupMetaDataSchema')) {
+        fail(`Unexpected error: ${e}`);
+      }
+    }
+
+    // 5. Keys without schema get a String schema registered
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data',
+      payload: { name: 'description', data: 'A group about tennis' },
+    });
+    const descriptionSchemaRecord: HolochainRecord | undefined = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_meta_data_schema',
+      payload: { input: 'description' },
+    });
+    const descriptionSchema = new EntryRecord<GroupMetaDataSchema>(descriptionSchemaRecord);
+    assert.deepEqual(descriptionSchema.entry.value_type, { type: 'String' });
+
+    const keys: string[] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'list_group_meta_data_keys',
+      payload: { input: null },
+    });
+    assert.deepEqual(keys, ['description', 'max_members']);
+  });
+});
+
+test('GroupMetaData keeps a revision history and can be reverted', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [alice, alicePubKey] = await installAppWithProgenitor(
+      scenario,
+      appBundleSource,
+      ['group'],
+      true,
+    );
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+
+    // 1. Alice sets the description twice
+    const first: HolochainRecord = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data',
+      payload: { name: 'description', data: 'A group about tennis' },
+    });
+    const second: HolochainRecord = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_meta_data',
+      payload: { name: 'description', data: 'oops' },
+    });
+
+    // 2. Alice reverts to the first revision, which updates the second one
+    const revert: HolochainRecord = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'revert_group_meta_data',
+      payload: {
+        revision_hash: first.signed_action.hashed.hash,
+        reason: 'Description got overwritten by accident',
+      },
+    });
+
+    assert.deepEqual(
+      (revert.signed_action.hashed.content as Update).original_action_address,
+      second.signed_action.hashed.hash,
+    );
+
+    const record: HolochainRecord | undefined = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_meta_data',
+      payload: { input: 'description' },
+    });
+    const latest = new EntryRecord<GroupMetaData>(record);
+    assert.equal(latest.entry.data, 'A group about tennis');
+    assert.deepEqual(latest.entry.revert.revision_hash, first.signed_action.hashed.hash);
+
+    // 3. All three revisions show up in the history, oldest first
+    const history: HolochainRecord[] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_meta_data_history',
+      payload: { input: 'description' },
+    });
+    const revisions = history.map((r) => new EntryRecord<GroupMetaData>(r));
+    assert.deepEqual(
+      revisions.map((r) => r.entry.data),
+      ['A group about tennis', 'oops', 'A group about tennis'],
+    );
+    revisions.forEach((r) => assert.deepEqual(r.action.author, alicePubKey));
+
+    // 4. A revert must re-publish the data of the revision it reverts to
+    try {
+      await groupCellAlice.callZome({
+        zome_name: 'group',
+        fn_name: 'set_group_meta_data',
+        payload: {
+          name: 'description',
+          data: 'something else',
+          revert: { revision_hash: first.signed_action.hashed.hash },
+        },
+      });
+      fail('A revert with different data than the reverted revision should be rejected.');
+    } catch (e) {
+      if (!e.toString().includes('same name and data as the revision')) {
+        fail(`Unexpected error: ${e}`);
+      }
+    }
+  });
+});
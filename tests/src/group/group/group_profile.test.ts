@@ -1,7 +1,12 @@
 import { assert, test } from 'vitest';
 
 import { runScenario, dhtSync } from '@holochain/tryorama';
-import { AppBundleSource, fakeAgentPubKey, Record as HolochainRecord } from '@holochain/client';
+import {
+  AppBundleSource,
+  EntryHash,
+  fakeAgentPubKey,
+  Record as HolochainRecord,
+} from '@holochain/client';
 import { EntryRecord } from '@holochain-open-dev/utils';
 
 import { getCellByRoleName, GROUP_HAPP_PATH } from '../../shared.js';
@@ -210,6 +215,139 @@ test('Can update group profile with valid steward permission', async () => {
   });
 });
 
+test('Group profile cannot have both an inline icon and an icon hash', async () => {
+  await runScenario(async (scenario) => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = GROUP_HAPP_PATH;
+
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: testAppPath,
+    };
+
+    // Set up the app to be installed
+    const appSource = { appBundleSource };
+
+    const [[alice, _alicePubKey], [_bob, _bobPubKey, _bobPermissionHash]] =
+      await twoAgentsOneProgenitorAndOneSteward(scenario, appSource.appBundleSource, ['group']);
+
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+
+    const iconHash: EntryHash = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'create_group_icon',
+      payload: { src: 'data:image/png;base64,iVBORw0KGgo=' },
+    });
+
+    // 1. Alice cannot create a group profile that also carries an inline icon
+    const input: GroupProfile = {
+      name: 'Tennis Club',
+      icon_src: 'base64pngetc',
+      icon_hash: iconHash,
+      meta_data: 'too meta to put here',
+      permission_hash: null,
+    };
+    try {
+      await groupCellAlice.callZome({
+        zome_name: 'group',
+        fn_name: 'set_group_profile',
+        payload: input,
+      });
+      fail(
+        'Alice should not be allowed to create a group profile with both icon_src and icon_hash.',
+      );
+    } catch (e) {
+      if (!e.toString().includes('A GroupProfile with an icon_hash cannot have an inline icon_src')) {
+        fail(
+        'Alice should not be allowed to create a group profile with both icon_src and icon_hash.',
+      );
+      }
+    }
+
+    // 2. Alice can create it with an empty inline icon
+    const input2: GroupProfile = { ...input, icon_src: '' };
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_profile',
+      payload: input2,
+    });
+
+    const groupProfileRecord: HolochainRecord | undefined = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_profile',
+      payload: { input: null },
+    });
+    assert(!!groupProfileRecord);
+    assert.deepEqual(new EntryRecord<GroupProfile>(groupProfileRecord).entry, input2);
+  });
+});
+
+test('Group profile updates form a history', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [[alice, alicePubKey], [bob, bobPubKey, bobPermissionHash]] =
+      await twoAgentsOneProgenitorAndOneSteward(scenario, appBundleSource, ['group']);
+
+    const groupCellBob = getCellByRoleName(bob, 'group');
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+
+    // 1. Alice creates the group profile
+    const input: GroupProfile = {
+      name: 'Tennis Club',
+      icon_src: 'base64pngetc',
+      meta_data: 'too meta to put here',
+      permission_hash: null,
+    };
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_profile',
+      payload: input,
+    });
+
+    await dhtSync([alice, bob], groupCellAlice.cell_id[0]);
+
+    // 2. Bob updates it with his steward permission
+    const input2: GroupProfile = {
+      name: 'Tennis Club 2',
+      icon_src: 'base64pngetc',
+      meta_data: 'too meta to put here',
+      permission_hash: bobPermissionHash,
+    };
+    await groupCellBob.callZome({
+      zome_name: 'group',
+      fn_name: 'set_group_profile',
+      payload: input2,
+    });
+
+    await dhtSync([alice, bob], groupCellAlice.cell_id[0]);
+
+    // Alice sees both revisions in order and the update as the latest profile
+    const history: HolochainRecord[] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_profile_history',
+      payload: { input: null },
+    });
+    assert.equal(history.length, 2);
+    const revisions = history.map((record) => new EntryRecord<GroupProfile>(record));
+    assert.deepEqual(revisions[0].entry, input);
+    assert.deepEqual(revisions[0].action.author, alicePubKey);
+    assert.deepEqual(revisions[1].entry, input2);
+    assert.deepEqual(revisions[1].action.author, bobPubKey);
+
+    const groupProfileRecord: HolochainRecord | undefined = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_group_profile',
+      payload: { input: null },
+    });
+    assert.deepEqual(new EntryRecord<GroupProfile>(groupProfileRecord).entry, input2);
+  });
+});
+
 test('Can update group profile as long as steward permission has not expired', async () => {
   await runScenario(async (scenario) => {
     // Construct proper paths for your app.
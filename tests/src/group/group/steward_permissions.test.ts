@@ -2,6 +2,7 @@ import { assert, test } from 'vitest';
 
 import { runScenario, dhtSync } from '@holochain/tryorama';
 import {
+  AgentPubKey,
   AppBundleSource,
   encodeHashToBase64,
   fakeAgentPubKey,
@@ -13,7 +14,12 @@ import {
   threeAgentsOneProgenitorOneStewardOneMember,
   twoAgentsOneProgenitorAndOneSteward,
 } from './common.js';
-import { Accountability, StewardPermission } from '@theweave/group-client';
+import {
+  Accountability,
+  GroupProfile,
+  StewardPermission,
+  StewardPermissionRevocation,
+} from '@theweave/group-client';
 import { fail } from 'assert';
 
 // Helper: pull the Steward variant out of a `Vec<Accountability>` response and
@@ -408,6 +414,164 @@ test('Steward can NOT nominate additional stewards without providing a valid per
   });
 });
 
+test('Progenitor can revoke a steward permission', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [[alice, _alicePubKey], [bob, bobPubKey, bobPermissionHash]] =
+      await twoAgentsOneProgenitorAndOneSteward(scenario, appBundleSource, ['group']);
+
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+    const groupCellBob = getCellByRoleName(bob, 'group');
+
+    // Bob queries himself once so that a StewardPermissionClaim gets cached on his chain.
+    const bobMyAccsBefore: Accountability[] = await groupCellBob.callZome({
+      zome_name: 'group',
+      fn_name: 'get_my_accountabilities',
+      payload: { input: Date.now() * 1000, local: false },
+    });
+    expectStewardAccountability(bobMyAccsBefore);
+
+    const beforeRevocation = Date.now() * 1000;
+
+    const input: StewardPermissionRevocation = {
+      revoked_permission_hash: bobPermissionHash,
+      reason: 'Stepped down',
+    };
+    await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'revoke_steward_permission',
+      payload: input,
+    });
+
+    await dhtSync([alice, bob], groupCellAlice.cell_id[0]);
+
+    // Bob is not a steward anymore from the revocation onward...
+    const bobAccsAfter: Accountability[] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_agent_accountabilities',
+      payload: { input: [bobPubKey, Date.now() * 1000], local: true },
+    });
+    assert(!bobAccsAfter.some((a) => a.type === 'Steward'));
+
+    // ...but still was one before the revocation
+    const bobAccsBeforeRevocation: Accountability[] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_agent_accountabilities',
+      payload: { input: [bobPubKey, beforeRevocation], local: true },
+    });
+    expectStewardAccountability(bobAccsBeforeRevocation);
+
+    // Bob's cached claim is not honoured anymore either
+    const bobMyAccsAfter: Accountability[] = await groupCellBob.callZome({
+      zome_name: 'group',
+      fn_name: 'get_my_accountabilities',
+      payload: { input: Date.now() * 1000, local: false },
+    });
+    assert(!bobMyAccsAfter.some((a) => a.type === 'Steward'));
+
+    const allAccs: [AgentPubKey, Accountability][] = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'get_all_agents_accountabilities',
+      payload: { input: Date.now() * 1000, local: true },
+    });
+    assert(!allAccs.some(([_agent, acc]) => acc.type === 'Steward'));
+
+    // Bob can no longer act based on the revoked permission
+    const groupProfile: GroupProfile = {
+      name: 'Tennis Club',
+      icon_src: 'base64pngetc',
+      meta_data: 'too meta to put here',
+      permission_hash: bobPermissionHash,
+    };
+    try {
+      await groupCellBob.callZome({
+        zome_name: 'group',
+        fn_name: 'set_group_profile',
+        payload: groupProfile,
+      });
+      fail('Bob should not be able to set the group profile with a revoked permission');
+    } catch (e) {
+      if (!e.toString().includes('StewardPermission has been revoked')) {
+        fail(`Expected validation rejection about the revoked permission, got: ${e}`);
+      }
+    }
+  });
+});
+
+test('Only the progenitor and stewards with non-expiring permissions can revoke steward permissions', async () => {
+  await runScenario(async (scenario) => {
+    const appBundleSource: AppBundleSource = {
+      type: 'path',
+      value: GROUP_HAPP_PATH,
+    };
+
+    const [[alice, _alicePubKey], [bob, _bobPubKey, bobPermissionHash], [charlie, charliePubKey]] =
+      await threeAgentsOneProgenitorOneStewardOneMember(scenario, appBundleSource, ['group']);
+
+    const groupCellAlice = getCellByRoleName(alice, 'group');
+    const groupCellBob = getCellByRoleName(bob, 'group');
+    const groupCellCharlie = getCellByRoleName(charlie, 'group');
+
+    // Bob issues an expiring permission to Charlie based on his own permission
+    const charliePermission: StewardPermission = {
+      for_agent: charliePubKey,
+      permission_hash: bobPermissionHash,
+      expiry: Date.now() * 1000 + 60 * 60 * 1_000_000,
+    };
+    const charliePermissionRecord: HolochainRecord = await groupCellBob.callZome({
+      zome_name: 'group',
+      fn_name: 'create_steward_permission',
+      payload: charliePermission,
+    });
+    const charliePermissionHash = charliePermissionRecord.signed_action.hashed.hash;
+
+    // Alice issues a permission to Carol
+    const carolPubKey = await fakeAgentPubKey();
+    const carolPermission: StewardPermission = {
+      for_agent: carolPubKey,
+    };
+    const carolPermissionRecord: HolochainRecord = await groupCellAlice.callZome({
+      zome_name: 'group',
+      fn_name: 'create_steward_permission',
+      payload: carolPermission,
+    });
+    const carolPermissionHash = carolPermissionRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob, charlie], groupCellAlice.cell_id[0]);
+
+    // Charlie cannot revoke Carol's permission since his own permission expires...
+    try {
+      await groupCellCharlie.callZome({
+        zome_name: 'group',
+        fn_name: 'revoke_steward_permission',
+        payload: {
+          permission_hash: charliePermissionHash,
+          revoked_permission_hash: carolPermissionHash,
+        } as StewardPermissionRevocation,
+      });
+      fail('Charlie should not be able to revoke a permission with an expiring permission');
+    } catch (e) {
+      if (!e.toString().includes('Only non-expiring StewardPermissions')) {
+        fail(`Expected validation rejection about the expiring permission, got: ${e}`);
+      }
+    }
+
+    // ...but Bob can, even though he has not issued it
+    await groupCellBob.callZome({
+      zome_name: 'group',
+      fn_name: 'revoke_steward_permission',
+      payload: {
+        permission_hash: bobPermissionHash,
+        revoked_permission_hash: carolPermissionHash,
+      } as StewardPermissionRevocation,
+    });
+  });
+});
+
 // TODO
 // - test that no steward permission returns an empty Accountability list
 // - test that StewardPermission entries cannot be created for oneself